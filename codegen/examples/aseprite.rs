@@ -0,0 +1,76 @@
+//! Regenerates `src/aseprite.rs` from Aseprite's `data/palettes` directory.
+//!
+//! ```sh
+//! cargo run -p bevy-color-codegen --example aseprite -- <aseprite>/data/palettes src/aseprite.rs
+//! ```
+
+use bevy_color_codegen::{Codegen, Error, read_dir};
+
+/// Hardware palettes, behind `aseprite-retro`
+const RETRO: [&str; 25] = [
+	"AppleIi",
+	"Atari2600Ntsc",
+	"Atari2600Pal",
+	"Cga",
+	"Cga0",
+	"Cga0hi",
+	"Cga1",
+	"Cga1hi",
+	"Cga3rd",
+	"Cga3rdhi",
+	"CommodorePlus4",
+	"CommodoreVic20",
+	"Commodore64",
+	"Cpc",
+	"GameboyColorType1",
+	"Gameboy",
+	"MasterSystem",
+	"Msx1",
+	"Msx2",
+	"NesNtsc",
+	"Nes",
+	"Teletext",
+	"Vga13h",
+	"Virtualboy",
+	"ZxSpectrum",
+];
+
+/// Software and theme palettes, behind `aseprite-software`
+const SOFTWARE: [&str; 8] = [
+	"GoogleUi",
+	"Minecraft",
+	"Monokai",
+	"SmileBasic",
+	"Solarized",
+	"WebSafeColors",
+	"Win16",
+	"X11",
+];
+
+fn main() -> Result<(), Error> {
+	let args: Vec<String> = std::env::args().skip(1).collect();
+	let [input, output] = args.as_slice() else {
+		eprintln!("Usage: aseprite <input_dir> <output.rs>");
+		std::process::exit(1);
+	};
+
+	let palettes = read_dir(input)?.into_iter().map(|palette| {
+		let feature = if RETRO.contains(&palette.name.as_str()) {
+			"aseprite-retro"
+		} else if SOFTWARE.contains(&palette.name.as_str()) {
+			"aseprite-software"
+		} else {
+			"aseprite-pixel-art"
+		};
+		palette.with_attr(format!("#[cfg(feature = \"{feature}\")]"))
+	});
+
+	let written = Codegen::new()
+		.macro_path("macros::palette")
+		.palettes(palettes)
+		.write(output)?;
+	if !written {
+		eprintln!("{output} is up to date");
+	}
+	Ok(())
+}
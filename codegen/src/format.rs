@@ -0,0 +1,200 @@
+//! Readers for the supported palette file formats
+
+use std::path::Path;
+
+use crate::{Error, PaletteSource};
+
+/// A palette file format
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+	/// GIMP and Aseprite palettes (`.gpl`), with an alpha column after `Channels: RGBA`
+	Gpl,
+	/// Lospec hex palettes (`.hex`), one `rrggbb` or `rrggbbaa` color per line
+	Hex,
+	/// Paint.NET palettes (`.txt`), one `aarrggbb` color per line and `;` comments
+	PaintNet,
+	/// JASC palettes (`.pal`), as written by Paint Shop Pro and Aseprite
+	JascPal,
+}
+
+impl Format {
+	/// Every supported format
+	pub const ALL: [Format; 4] = [Format::Gpl, Format::Hex, Format::PaintNet, Format::JascPal];
+
+	/// The file extension of the format, without the dot
+	#[must_use]
+	pub const fn extension(self) -> &'static str {
+		match self {
+			Format::Gpl => "gpl",
+			Format::Hex => "hex",
+			Format::PaintNet => "txt",
+			Format::JascPal => "pal",
+		}
+	}
+
+	/// Guesses the format of a file from its extension, ignoring case
+	#[must_use]
+	pub fn from_path(path: &Path) -> Option<Self> {
+		let extension = path.extension()?.to_str()?;
+		Self::ALL
+			.into_iter()
+			.find(|format| format.extension().eq_ignore_ascii_case(extension))
+	}
+
+	/// Parses the colors of a palette file, in file order
+	///
+	/// Colors keep their names from the file where the format has them, and are otherwise named
+	/// after their hex value. Names are made unique by [`PaletteSource::new`].
+	///
+	/// # Errors
+	/// - If a color line is malformed.
+	pub fn parse(self, name: &str, text: &str) -> Result<PaletteSource, Error> {
+		let colors = match self {
+			Format::Gpl => parse_gpl(name, text)?,
+			Format::Hex => parse_hex(name, text)?,
+			Format::PaintNet => parse_paint_net(name, text)?,
+			Format::JascPal => parse_jasc_pal(name, text)?,
+		};
+		Ok(PaletteSource::new(name, colors))
+	}
+}
+
+type Colors = Vec<(String, [u8; 4])>;
+
+fn parse_error(name: &str, line: usize, message: impl Into<String>) -> Error {
+	Error::Parse {
+		palette: name.to_string(),
+		line: line + 1,
+		message: message.into(),
+	}
+}
+
+fn parse_gpl(name: &str, text: &str) -> Result<Colors, Error> {
+	let mut colors = Vec::new();
+	let mut rgba = false;
+
+	for (index, line) in text.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() || line.starts_with('#') {
+			continue;
+		}
+		if let Some(channels) = line.strip_prefix("Channels:") {
+			rgba = channels.trim().eq_ignore_ascii_case("RGBA");
+			continue;
+		}
+		// Headers such as `GIMP Palette`, `Name: ...` and `Columns: ...` don't start with a number
+		if !line.starts_with(|c: char| c.is_ascii_digit()) {
+			continue;
+		}
+
+		let mut parts = line.split_whitespace();
+		let channel_count = if rgba { 4 } else { 3 };
+		let mut channels = [0, 0, 0, 255];
+		for channel in &mut channels[..channel_count] {
+			*channel = parts
+				.next()
+				.and_then(|part| part.parse().ok())
+				.ok_or_else(|| {
+					parse_error(
+						name,
+						index,
+						format!("expected {channel_count} channels from 0 to 255"),
+					)
+				})?;
+		}
+		let color_name = parts.collect::<Vec<_>>().join(" ");
+		colors.push((color_name, channels));
+	}
+
+	Ok(colors)
+}
+
+fn parse_hex(name: &str, text: &str) -> Result<Colors, Error> {
+	let mut colors = Vec::new();
+
+	for (index, line) in text.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() {
+			continue;
+		}
+		let hex = line.strip_prefix('#').unwrap_or(line);
+		let rgba = match hex.len() {
+			6 => parse_hex_channels(hex).map(|[r, g, b]| [r, g, b, 255]),
+			8 => parse_hex_channels(hex),
+			_ => None,
+		}
+		.ok_or_else(|| parse_error(name, index, "expected a `rrggbb` or `rrggbbaa` color"))?;
+		colors.push((String::new(), rgba));
+	}
+
+	Ok(colors)
+}
+
+fn parse_paint_net(name: &str, text: &str) -> Result<Colors, Error> {
+	let mut colors = Vec::new();
+
+	for (index, line) in text.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() || line.starts_with(';') {
+			continue;
+		}
+		let [a, r, g, b] = Some(line)
+			.filter(|hex| hex.len() == 8)
+			.and_then(parse_hex_channels)
+			.ok_or_else(|| parse_error(name, index, "expected an `aarrggbb` color"))?;
+		colors.push((String::new(), [r, g, b, a]));
+	}
+
+	Ok(colors)
+}
+
+fn parse_jasc_pal(name: &str, text: &str) -> Result<Colors, Error> {
+	let mut lines = text.lines().map(str::trim).enumerate();
+
+	if lines.next().map(|(_, line)| line) != Some("JASC-PAL") {
+		return Err(parse_error(name, 0, "expected a `JASC-PAL` header"));
+	}
+	// The version, always `0100`, then the number of colors
+	lines.next();
+	let count = lines
+		.next()
+		.and_then(|(_, line)| line.parse::<usize>().ok())
+		.ok_or_else(|| parse_error(name, 2, "expected the number of colors"))?;
+
+	let mut colors = Vec::with_capacity(count);
+	for (index, line) in lines.filter(|(_, line)| !line.is_empty()).take(count) {
+		let channels = line
+			.split_whitespace()
+			.map(str::parse::<u8>)
+			.collect::<Result<Vec<_>, _>>()
+			.ok()
+			.filter(|channels| channels.len() == 3 || channels.len() == 4)
+			.ok_or_else(|| parse_error(name, index, "expected 3 or 4 channels from 0 to 255"))?;
+		let alpha = channels.get(3).copied().unwrap_or(255);
+		colors.push((
+			String::new(),
+			[channels[0], channels[1], channels[2], alpha],
+		));
+	}
+	if colors.len() < count {
+		return Err(parse_error(
+			name,
+			text.lines().count().saturating_sub(1),
+			format!("expected {count} colors, found {}", colors.len()),
+		));
+	}
+
+	Ok(colors)
+}
+
+/// Parses pairs of hex digits into channels
+fn parse_hex_channels<const N: usize>(hex: &str) -> Option<[u8; N]> {
+	if hex.len() != N * 2 || !hex.is_ascii() {
+		return None;
+	}
+	let mut channels = [0; N];
+	for (i, channel) in channels.iter_mut().enumerate() {
+		*channel = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+	}
+	Some(channels)
+}
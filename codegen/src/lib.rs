@@ -0,0 +1,326 @@
+//! Build-script codegen for bevy-color-palettes
+//!
+//! Turns a directory of palette files into `palette!` invocations, for use from `build.rs` or an
+//! xtask. Names are derived deterministically from the file and color names, so regenerating an
+//! unchanged directory produces identical source.
+//!
+//! ```no_run
+//! // build.rs
+//! fn main() -> Result<(), bevy_color_codegen::Error> {
+//!     let out_dir = std::env::var("OUT_DIR").unwrap();
+//!     bevy_color_codegen::Codegen::new()
+//!         .dir("palettes")?
+//!         .write(format!("{out_dir}/palettes.rs"))?;
+//!     println!("cargo::rerun-if-changed=palettes");
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ```ignore
+//! // src/palettes.rs
+//! include!(concat!(env!("OUT_DIR"), "/palettes.rs"));
+//! ```
+//!
+//! # Names
+//!
+//! - Palettes are named after their file in `PascalCase`, e.g. `aap-64.gpl` becomes `Aap64`.
+//! - Colors are named in `snake_case` after their name in the file. Unnamed and `Untitled`
+//!   colors are named `color_rrggbbaa`, and colors named after their own hex value get a `color_`
+//!   prefix, keeping the 6 or 8 digits they were given. These match the names of the existing
+//!   generated palettes, such as `aseprite::A64::COLOR_000000FF`.
+//! - Repeated colors with the same name are dropped. Other names that `palette!` would look up
+//!   as the same color get a `_2`, `_3`, ... suffix in file order.
+//! - Names `palette!` reserves, such as `len`, get a `_color` suffix.
+
+mod format;
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub use format::Format;
+
+/// An error reading palette files or generating source
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("failed to access {path}: {source}")]
+	Io {
+		path: PathBuf,
+		source: std::io::Error,
+	},
+	#[error("unsupported palette file {0}")]
+	UnsupportedFormat(PathBuf),
+	#[error("palette {palette}, line {line}: {message}")]
+	Parse {
+		palette: String,
+		line: usize,
+		message: String,
+	},
+	#[error("more than one palette is named {0}")]
+	DuplicatePalette(String),
+}
+
+/// A palette ready to be written as a `palette!` invocation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaletteSource {
+	/// The palette name, a `PascalCase` identifier
+	pub name: String,
+	/// Outer attributes written before the palette, e.g. `#[cfg(feature = "retro")]`
+	pub attrs: Vec<String>,
+	/// The unique color names and their RGBA values, in file order
+	pub colors: Vec<(String, [u8; 4])>,
+}
+
+impl PaletteSource {
+	/// Creates a palette from raw names, applying the naming and de-duplication rules
+	#[must_use]
+	pub fn new(name: &str, colors: impl IntoIterator<Item = (String, [u8; 4])>) -> Self {
+		let mut unique = Vec::new();
+		let mut seen = HashSet::new();
+		let mut normalized_names = HashSet::new();
+
+		for (color_name, rgba) in colors {
+			let base = color_name_for(&color_name, rgba);
+			if !seen.insert((base.clone(), rgba)) {
+				continue;
+			}
+
+			let mut unique_name = base.clone();
+			let mut suffix = 2;
+			while !normalized_names.insert(normalize(&unique_name)) {
+				unique_name = format!("{base}_{suffix}");
+				suffix += 1;
+			}
+			unique.push((unique_name, rgba));
+		}
+
+		Self {
+			name: pascal_case(name),
+			attrs: Vec::new(),
+			colors: unique,
+		}
+	}
+
+	/// Adds an outer attribute, e.g. `#[cfg(feature = "retro")]`
+	#[must_use]
+	pub fn with_attr(mut self, attr: impl Into<String>) -> Self {
+		self.attrs.push(attr.into());
+		self
+	}
+}
+
+/// Reads a palette file, guessing its format from the extension
+///
+/// # Errors
+/// - If the file can't be read, has an unsupported extension or is malformed.
+pub fn read_file(path: impl AsRef<Path>) -> Result<PaletteSource, Error> {
+	let path = path.as_ref();
+	let format = Format::from_path(path).ok_or_else(|| Error::UnsupportedFormat(path.into()))?;
+	let text = fs::read(path).map_err(|source| Error::Io {
+		path: path.into(),
+		source,
+	})?;
+	let name = path
+		.file_stem()
+		.map(|stem| stem.to_string_lossy())
+		.unwrap_or_default();
+	format.parse(&name, &String::from_utf8_lossy(&text))
+}
+
+/// Reads every supported palette file under `dir`, recursively and sorted by path
+///
+/// Files with other extensions and palettes without colors are skipped.
+///
+/// # Errors
+/// - If a directory or file can't be read, or a palette file is malformed.
+pub fn read_dir(dir: impl AsRef<Path>) -> Result<Vec<PaletteSource>, Error> {
+	let mut paths = Vec::new();
+	collect_paths(dir.as_ref(), &mut paths)?;
+	paths.sort();
+
+	let mut palettes = Vec::new();
+	for path in paths {
+		let palette = read_file(path)?;
+		if !palette.colors.is_empty() {
+			palettes.push(palette);
+		}
+	}
+	Ok(palettes)
+}
+
+fn collect_paths(dir: &Path, paths: &mut Vec<PathBuf>) -> Result<(), Error> {
+	let io_error = |source| Error::Io {
+		path: dir.into(),
+		source,
+	};
+	for entry in fs::read_dir(dir).map_err(io_error)? {
+		let path = entry.map_err(io_error)?.path();
+		if path.is_dir() {
+			collect_paths(&path, paths)?;
+		} else if Format::from_path(&path).is_some() {
+			paths.push(path);
+		}
+	}
+	Ok(())
+}
+
+/// Generates a Rust module of `palette!` invocations
+#[derive(Clone, Debug)]
+pub struct Codegen {
+	macro_path: String,
+	palettes: Vec<PaletteSource>,
+}
+
+impl Default for Codegen {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Codegen {
+	/// Creates an empty module that imports `bevy_color_palettes::palette`
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			macro_path: "bevy_color_palettes::palette".into(),
+			palettes: Vec::new(),
+		}
+	}
+
+	/// Sets the path the module imports the `palette!` macro from
+	#[must_use]
+	pub fn macro_path(mut self, path: impl Into<String>) -> Self {
+		self.macro_path = path.into();
+		self
+	}
+
+	/// Adds a palette
+	#[must_use]
+	pub fn palette(mut self, palette: PaletteSource) -> Self {
+		self.palettes.push(palette);
+		self
+	}
+
+	/// Adds palettes, e.g. from [`read_dir`]
+	#[must_use]
+	pub fn palettes(mut self, palettes: impl IntoIterator<Item = PaletteSource>) -> Self {
+		self.palettes.extend(palettes);
+		self
+	}
+
+	/// Adds every palette under `dir`, see [`read_dir`]
+	///
+	/// # Errors
+	/// - If [`read_dir`] fails.
+	pub fn dir(self, dir: impl AsRef<Path>) -> Result<Self, Error> {
+		Ok(self.palettes(read_dir(dir)?))
+	}
+
+	/// Generates the module source
+	///
+	/// # Errors
+	/// - If two palettes have the same name.
+	pub fn generate(&self) -> Result<String, Error> {
+		let mut names = HashSet::new();
+		for palette in &self.palettes {
+			if !names.insert(palette.name.as_str()) {
+				return Err(Error::DuplicatePalette(palette.name.clone()));
+			}
+		}
+
+		let mut source = format!("use {};\n", self.macro_path);
+		let macro_name = self.macro_path.rsplit("::").next().unwrap_or_default();
+		for palette in &self.palettes {
+			source.push('\n');
+			for attr in &palette.attrs {
+				source.push_str(attr);
+				source.push('\n');
+			}
+			let _ = writeln!(source, "{macro_name}!({} {{", palette.name);
+			for (name, [r, g, b, a]) in &palette.colors {
+				let _ = writeln!(source, "\t\"{name}\": \"#{r:02x}{g:02x}{b:02x}{a:02x}\",");
+			}
+			source.push_str("});\n");
+		}
+		Ok(source)
+	}
+
+	/// Writes the module source to `path`, leaving the file untouched if it is already up to date
+	///
+	/// Returns whether the file was written, so unchanged output doesn't trigger rebuilds.
+	///
+	/// # Errors
+	/// - If [`Codegen::generate`] fails or the file can't be written.
+	pub fn write(&self, path: impl AsRef<Path>) -> Result<bool, Error> {
+		let path = path.as_ref();
+		let source = self.generate()?;
+		if fs::read_to_string(path).is_ok_and(|existing| existing == source) {
+			return Ok(false);
+		}
+		fs::write(path, source).map_err(|source| Error::Io {
+			path: path.into(),
+			source,
+		})?;
+		Ok(true)
+	}
+}
+
+/// Names `palette!` rejects or generates methods for
+const RESERVED_NAMES: [&str; 7] = ["all", "get", "iter", "len", "self", "super", "crate"];
+
+fn color_name_for(name: &str, [r, g, b, a]: [u8; 4]) -> String {
+	let name = snake_case(name);
+	let hex = format!("{r:02x}{g:02x}{b:02x}");
+	let full_hex = format!("{hex}{a:02x}");
+
+	if name.is_empty() || name == "untitled" {
+		format!("color_{full_hex}")
+	} else if name == hex || name == full_hex {
+		format!("color_{name}")
+	} else if RESERVED_NAMES.contains(&name.as_str()) {
+		format!("{name}_color")
+	} else {
+		name
+	}
+}
+
+/// Lowercases ASCII letters and digits, joining everything else with single underscores
+fn snake_case(name: &str) -> String {
+	let mut snake = String::new();
+	for word in name
+		.split(|c: char| !c.is_ascii_alphanumeric())
+		.filter(|word| !word.is_empty())
+	{
+		if !snake.is_empty() {
+			snake.push('_');
+		}
+		snake.push_str(&word.to_ascii_lowercase());
+	}
+	snake
+}
+
+/// Capitalizes every ASCII alphanumeric word and joins them, prefixing a leading digit
+fn pascal_case(name: &str) -> String {
+	let mut pascal = String::new();
+	for word in name
+		.split(|c: char| !c.is_ascii_alphanumeric())
+		.filter(|word| !word.is_empty())
+	{
+		let mut chars = word.chars();
+		pascal.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+		pascal.push_str(&chars.as_str().to_ascii_lowercase());
+	}
+	if pascal.is_empty() || pascal.starts_with(|c: char| c.is_ascii_digit()) {
+		pascal.insert_str(0, "Palette");
+	}
+	pascal
+}
+
+/// The key `palette!` compares names by
+fn normalize(name: &str) -> String {
+	name.chars()
+		.filter(char::is_ascii_alphanumeric)
+		.map(|c| c.to_ascii_lowercase())
+		.collect()
+}
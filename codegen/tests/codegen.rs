@@ -0,0 +1,176 @@
+use std::fs;
+
+use bevy_color_codegen::{Codegen, Error, Format, PaletteSource, read_dir};
+
+#[test]
+fn test_parse_formats() {
+	let gpl =
+		"GIMP Palette\nName: Test\nColumns: 2\n# comment\n255   0   0\tRed\n  0 255   0\tGreen\n";
+	let hex = "ff0000\n#00ff0080\n";
+	let paint_net = "; paint.net palette\nFFFF0000\n8000FF00\n";
+	let jasc = "JASC-PAL\n0100\n2\n255 0 0\n0 255 0 128\n";
+
+	assert_eq!(
+		Format::Gpl.parse("test", gpl).unwrap().colors,
+		[
+			("red".to_string(), [255, 0, 0, 255]),
+			("green".to_string(), [0, 255, 0, 255])
+		]
+	);
+	let expected = [
+		("color_ff0000ff".to_string(), [255, 0, 0, 255]),
+		("color_00ff0080".to_string(), [0, 255, 0, 128]),
+	];
+	assert_eq!(Format::Hex.parse("test", hex).unwrap().colors, expected);
+	assert_eq!(
+		Format::PaintNet.parse("test", paint_net).unwrap().colors,
+		expected
+	);
+	assert_eq!(
+		Format::JascPal.parse("test", jasc).unwrap().colors,
+		expected
+	);
+
+	let rgba = "GIMP Palette\nChannels: RGBA\n255 0 0 128 Red\n";
+	assert_eq!(
+		Format::Gpl.parse("test", rgba).unwrap().colors,
+		[("red".to_string(), [255, 0, 0, 128])]
+	);
+
+	assert!(matches!(
+		Format::Hex.parse("test", "ff0000\nnope\n"),
+		Err(Error::Parse { line: 2, .. })
+	));
+	assert!(matches!(
+		Format::JascPal.parse("test", "JASC-PAL\n0100\n3\n255 0 0\n"),
+		Err(Error::Parse { .. })
+	));
+}
+
+#[test]
+fn test_names() {
+	let palette = PaletteSource::new(
+		"aap-splendor128",
+		[
+			("Dark Gray".to_string(), [64, 64, 64, 255]),
+			("Dark Gray".to_string(), [64, 64, 64, 255]),
+			("dark-gray".to_string(), [70, 70, 70, 255]),
+			("Untitled".to_string(), [1, 2, 3, 255]),
+			("010203".to_string(), [1, 2, 3, 255]),
+			(String::new(), [1, 2, 3, 4]),
+			("len".to_string(), [9, 9, 9, 255]),
+		],
+	);
+
+	assert_eq!(palette.name, "AapSplendor128");
+	let names: Vec<_> = palette
+		.colors
+		.iter()
+		.map(|(name, _)| name.as_str())
+		.collect();
+	assert_eq!(
+		names,
+		[
+			"dark_gray",
+			"dark_gray_2",
+			"color_010203ff",
+			"color_010203",
+			"color_01020304",
+			"len_color"
+		]
+	);
+	assert_eq!(PaletteSource::new("8-bit", []).name, "Palette8Bit");
+}
+
+#[test]
+fn test_names_match_generated_aseprite_palettes() {
+	// The first colors of Aseprite's `a64.gpl` and `aap-64.gpl`, which name their colors
+	// `Untitled` and after their hex value
+	let a64 = Format::Gpl
+		.parse(
+			"a64",
+			"GIMP Palette\nName: A64\n#\n  0   0   0\tUntitled\n 49  58 145\tUntitled\n",
+		)
+		.unwrap();
+	let aap64 = Format::Gpl
+		.parse(
+			"aap-64",
+			"GIMP Palette\n  6   6   8\t060608\n 20  16  19\t141013\n",
+		)
+		.unwrap();
+	let source = Codegen::new()
+		.macro_path("macros::palette")
+		.palette(a64)
+		.palette(aap64)
+		.generate()
+		.unwrap();
+
+	let existing = include_str!("../../src/aseprite.rs");
+	let color_lines: Vec<_> = source
+		.lines()
+		.filter(|line| line.starts_with('\t'))
+		.collect();
+	assert_eq!(
+		color_lines,
+		[
+			"\t\"color_000000ff\": \"#000000ff\",",
+			"\t\"color_313a91ff\": \"#313a91ff\",",
+			"\t\"color_060608\": \"#060608ff\",",
+			"\t\"color_141013\": \"#141013ff\",",
+		]
+	);
+	for line in color_lines {
+		assert!(existing.contains(line), "{line} isn't in src/aseprite.rs");
+	}
+}
+
+#[test]
+fn test_generate() {
+	let source = Codegen::new()
+		.macro_path("macros::palette")
+		.palette(
+			PaletteSource::new("my palette", [("Red".to_string(), [255, 0, 0, 255])])
+				.with_attr("#[cfg(feature = \"retro\")]"),
+		)
+		.generate()
+		.unwrap();
+	assert_eq!(
+		source,
+		"use macros::palette;\n\n#[cfg(feature = \"retro\")]\npalette!(MyPalette {\n\t\"red\": \"#ff0000ff\",\n});\n"
+	);
+
+	let duplicate = Codegen::new()
+		.palette(PaletteSource::new("a", []))
+		.palette(PaletteSource::new("A", []))
+		.generate();
+	assert!(matches!(duplicate, Err(Error::DuplicatePalette(name)) if name == "A"));
+}
+
+#[test]
+fn test_read_dir_and_write() {
+	let dir = std::env::temp_dir().join(format!("bevy-color-codegen-{}", std::process::id()));
+	let _ = fs::remove_dir_all(&dir);
+	fs::create_dir_all(dir.join("nested")).unwrap();
+	fs::write(dir.join("b.hex"), "0000ff\n").unwrap();
+	fs::write(dir.join("nested/a.gpl"), "GIMP Palette\n255 0 0 Red\n").unwrap();
+	fs::write(dir.join("empty.hex"), "").unwrap();
+	fs::write(dir.join("notes.md"), "not a palette").unwrap();
+
+	let palettes = read_dir(&dir).unwrap();
+	let names: Vec<_> = palettes
+		.iter()
+		.map(|palette| palette.name.as_str())
+		.collect();
+	assert_eq!(names, ["B", "A"]);
+
+	let output = dir.join("palettes.rs");
+	let codegen = Codegen::new().palettes(palettes);
+	assert!(codegen.write(&output).unwrap());
+	assert!(!codegen.write(&output).unwrap());
+	assert_eq!(
+		fs::read_to_string(&output).unwrap(),
+		codegen.generate().unwrap()
+	);
+
+	fs::remove_dir_all(&dir).unwrap();
+}
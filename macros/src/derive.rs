@@ -0,0 +1,353 @@
+//! Derived palette entries, e.g. `"hover": lighten(primary, 0.1)`, evaluated at compile time
+
+use std::collections::HashMap;
+
+use proc_macro2::Span;
+use syn::token::{Comma, Minus, Paren};
+use syn::{
+	Ident, Lit, LitStr, Result, parenthesized,
+	parse::{Parse, ParseStream},
+};
+
+use crate::{PaletteDef, normalize_color_name, parse_hex_color};
+
+/// A color expression inside `palette!`
+pub(crate) enum ColorExpr {
+	/// A hex color literal, e.g. `"#ff0000"`
+	Rgba([u8; 4], Span),
+	/// A reference to another entry by name, e.g. `primary` or `"primary"`
+	Ref(String, Span),
+	/// A transform applied to other colors, e.g. `mix(a, b, 0.5)`
+	Call(Ident, Vec<Arg>),
+}
+
+/// An argument of a color transform
+pub(crate) enum Arg {
+	Color(ColorExpr),
+	Number(f64, Span),
+}
+
+impl Parse for ColorExpr {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if input.peek(LitStr) {
+			// Hex colors start with '#', anything else refers to another entry
+			let lit = input.parse::<LitStr>()?;
+			let value = lit.value();
+			if value.starts_with('#') {
+				let (r, g, b, a) = parse_hex_color(&value, lit.span())?;
+				Ok(ColorExpr::Rgba([r, g, b, a], lit.span()))
+			} else {
+				Ok(ColorExpr::Ref(value, lit.span()))
+			}
+		} else {
+			let ident = input.parse::<Ident>()?;
+			if input.peek(Paren) {
+				let content;
+				parenthesized!(content in input);
+				let args = content.parse_terminated(Arg::parse, Comma)?;
+				Ok(ColorExpr::Call(ident, args.into_iter().collect()))
+			} else {
+				Ok(ColorExpr::Ref(ident.to_string(), ident.span()))
+			}
+		}
+	}
+}
+
+impl Parse for Arg {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if input.peek(Lit) && !input.peek(LitStr) || input.peek(Minus) {
+			let negative = input.parse::<Option<Minus>>()?.is_some();
+			let (value, span) = match input.parse::<Lit>()? {
+				Lit::Float(lit) => (lit.base10_parse::<f64>()?, lit.span()),
+				Lit::Int(lit) => (lit.base10_parse::<f64>()?, lit.span()),
+				lit => return Err(syn::Error::new(lit.span(), "Expected a number.")),
+			};
+			Ok(Arg::Number(if negative { -value } else { value }, span))
+		} else {
+			input.parse().map(Arg::Color)
+		}
+	}
+}
+
+/// Evaluate every derived color of a palette in place, following references in any order
+pub(crate) fn resolve_derived(palette_def: &mut PaletteDef) -> Result<()> {
+	let mut names = HashMap::new();
+	for (index, color) in palette_def.colors.iter().enumerate() {
+		names.insert(normalize_color_name(&color.name), index);
+	}
+	for alias in &palette_def.aliases {
+		if let Some(&index) = names.get(&normalize_color_name(&alias.target.value())) {
+			names.insert(normalize_color_name(&alias.name), index);
+		}
+	}
+
+	let mut resolver = Resolver {
+		names,
+		exprs: palette_def
+			.colors
+			.iter_mut()
+			.map(|color| color.derived.take())
+			.collect(),
+		values: palette_def
+			.colors
+			.iter()
+			.map(|color| Some([color.r8, color.g8, color.b8, color.a8]))
+			.collect(),
+		visiting: vec![false; palette_def.colors.len()],
+	};
+	for (index, expr) in resolver.exprs.iter().enumerate() {
+		if expr.is_some() {
+			resolver.values[index] = None;
+		}
+	}
+
+	for index in 0..palette_def.colors.len() {
+		let [r8, g8, b8, a8] = resolver.color(index, palette_def.colors[index].name_span)?;
+		let color = &mut palette_def.colors[index];
+		color.r8 = r8;
+		color.g8 = g8;
+		color.b8 = b8;
+		color.a8 = a8;
+	}
+	Ok(())
+}
+
+struct Resolver {
+	names: HashMap<String, usize>,
+	exprs: Vec<Option<ColorExpr>>,
+	values: Vec<Option<[u8; 4]>>,
+	visiting: Vec<bool>,
+}
+
+impl Resolver {
+	/// The value of the entry at `index`, evaluating it first if it is derived
+	fn color(&mut self, index: usize, span: Span) -> Result<[u8; 4]> {
+		if let Some(value) = self.values[index] {
+			return Ok(value);
+		}
+		if self.visiting[index] {
+			return Err(syn::Error::new(span, "Derived color depends on itself."));
+		}
+
+		self.visiting[index] = true;
+		let expr = self.exprs[index]
+			.take()
+			.expect("unresolved colors are derived");
+		let value = self.eval(&expr).map(Oklab::to_rgba8);
+		self.exprs[index] = Some(expr);
+		self.visiting[index] = false;
+
+		let value = value?;
+		self.values[index] = Some(value);
+		Ok(value)
+	}
+
+	fn eval(&mut self, expr: &ColorExpr) -> Result<Oklab> {
+		match expr {
+			ColorExpr::Rgba(rgba, _) => Ok(Oklab::from_rgba8(*rgba)),
+			ColorExpr::Ref(name, span) => {
+				let Some(&index) = self.names.get(&normalize_color_name(name)) else {
+					return Err(syn::Error::new(
+						*span,
+						format!("No color named {name:?} in this palette."),
+					));
+				};
+				self.color(index, *span).map(Oklab::from_rgba8)
+			}
+			ColorExpr::Call(func, args) => self.call(func, args),
+		}
+	}
+
+	fn call(&mut self, func: &Ident, args: &[Arg]) -> Result<Oklab> {
+		let name = func.to_string();
+		let arity = |expected: usize| {
+			if args.len() == expected {
+				Ok(())
+			} else {
+				Err(syn::Error::new(
+					func.span(),
+					format!("`{name}` takes {expected} arguments."),
+				))
+			}
+		};
+
+		match name.as_str() {
+			"lighten" | "darken" | "saturate" | "desaturate" | "rotate_hue" | "with_alpha" => {
+				arity(2)?;
+				let color = self.color_arg(&args[0])?;
+				let amount = number_arg(&args[1])?;
+				Ok(match name.as_str() {
+					"lighten" => color.with_lightness(color.l + amount),
+					"darken" => color.with_lightness(color.l - amount),
+					"saturate" => color.with_chroma_scale(1.0 + amount),
+					"desaturate" => color.with_chroma_scale(1.0 - amount),
+					"rotate_hue" => color.with_hue_rotation(amount),
+					_ => Oklab {
+						alpha: amount.clamp(0.0, 1.0),
+						..color
+					},
+				})
+			}
+			"mix" => {
+				arity(3)?;
+				let a = self.color_arg(&args[0])?;
+				let b = self.color_arg(&args[1])?;
+				let t = number_arg(&args[2])?;
+				Ok(a.mix(b, t))
+			}
+			"alias" => Err(syn::Error::new(
+				func.span(),
+				"`alias` is only allowed as the whole value of an entry; refer to the color by name instead.",
+			)),
+			_ => Err(syn::Error::new(
+				func.span(),
+				format!(
+					"Unknown color function `{name}`; expected `lighten`, `darken`, `saturate`, `desaturate`, `rotate_hue`, `with_alpha` or `mix`."
+				),
+			)),
+		}
+	}
+
+	fn color_arg(&mut self, arg: &Arg) -> Result<Oklab> {
+		match arg {
+			Arg::Color(expr) => self.eval(expr),
+			Arg::Number(_, span) => Err(syn::Error::new(*span, "Expected a color.")),
+		}
+	}
+}
+
+fn number_arg(arg: &Arg) -> Result<f64> {
+	match arg {
+		Arg::Number(value, _) => Ok(*value),
+		Arg::Color(ColorExpr::Rgba(_, span) | ColorExpr::Ref(_, span)) => {
+			Err(syn::Error::new(*span, "Expected a number."))
+		}
+		Arg::Color(ColorExpr::Call(func, _)) => {
+			Err(syn::Error::new(func.span(), "Expected a number."))
+		}
+	}
+}
+
+/// A color in the Oklab perceptual color space, with straight alpha
+#[derive(Clone, Copy)]
+struct Oklab {
+	l: f64,
+	a: f64,
+	b: f64,
+	alpha: f64,
+}
+
+impl Oklab {
+	#[allow(clippy::many_single_char_names)]
+	fn from_rgba8([r, g, b, a]: [u8; 4]) -> Self {
+		let [r, g, b] = [r, g, b].map(|c| srgb_to_linear(f64::from(c) / 255.0));
+
+		let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+		let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+		let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+
+		Self {
+			l: 0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+			a: 1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+			b: 0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+			alpha: f64::from(a) / 255.0,
+		}
+	}
+
+	fn to_linear_rgb(self) -> [f64; 3] {
+		let l = (self.l + 0.396_337_777_4 * self.a + 0.215_803_757_3 * self.b).powi(3);
+		let m = (self.l - 0.105_561_345_8 * self.a - 0.063_854_172_8 * self.b).powi(3);
+		let s = (self.l - 0.089_484_177_5 * self.a - 1.291_485_548_0 * self.b).powi(3);
+
+		[
+			4.076_741_662_1 * l - 3.307_711_591_3 * m + 0.230_969_929_2 * s,
+			-1.268_438_004_6 * l + 2.609_757_401_1 * m - 0.341_319_396_5 * s,
+			-0.004_196_086_3 * l - 0.703_418_614_7 * m + 1.707_614_701_0 * s,
+		]
+	}
+
+	fn in_gamut(self) -> bool {
+		self.to_linear_rgb()
+			.iter()
+			.all(|c| (-1e-6..=1.0 + 1e-6).contains(c))
+	}
+
+	/// Convert to 8-bit sRGB, reducing chroma at constant lightness and hue until the color fits
+	fn to_rgba8(self) -> [u8; 4] {
+		let mut color = self;
+		if !color.in_gamut() {
+			let (mut low, mut high) = (0.0, 1.0);
+			for _ in 0..32 {
+				let mid = f64::midpoint(low, high);
+				if self.scale_chroma(mid).in_gamut() {
+					low = mid;
+				} else {
+					high = mid;
+				}
+			}
+			color = self.scale_chroma(low);
+		}
+
+		let [r, g, b] = color.to_linear_rgb().map(|c| to_u8(linear_to_srgb(c)));
+		[r, g, b, to_u8(color.alpha)]
+	}
+
+	fn scale_chroma(self, scale: f64) -> Self {
+		Self {
+			a: self.a * scale,
+			b: self.b * scale,
+			..self
+		}
+	}
+
+	fn with_lightness(self, l: f64) -> Self {
+		Self {
+			l: l.clamp(0.0, 1.0),
+			..self
+		}
+	}
+
+	fn with_chroma_scale(self, scale: f64) -> Self {
+		self.scale_chroma(scale.max(0.0))
+	}
+
+	fn with_hue_rotation(self, degrees: f64) -> Self {
+		let (sin, cos) = degrees.to_radians().sin_cos();
+		Self {
+			a: self.a * cos - self.b * sin,
+			b: self.a * sin + self.b * cos,
+			..self
+		}
+	}
+
+	fn mix(self, other: Self, t: f64) -> Self {
+		let lerp = |a: f64, b: f64| a + (b - a) * t;
+		Self {
+			l: lerp(self.l, other.l),
+			a: lerp(self.a, other.a),
+			b: lerp(self.b, other.b),
+			alpha: lerp(self.alpha, other.alpha),
+		}
+	}
+}
+
+fn srgb_to_linear(c: f64) -> f64 {
+	if c <= 0.040_45 {
+		c / 12.92
+	} else {
+		((c + 0.055) / 1.055).powf(2.4)
+	}
+}
+
+fn linear_to_srgb(c: f64) -> f64 {
+	if c <= 0.003_130_8 {
+		c * 12.92
+	} else {
+		1.055 * c.powf(1.0 / 2.4) - 0.055
+	}
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_u8(c: f64) -> u8 {
+	(c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
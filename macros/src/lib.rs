@@ -1,13 +1,16 @@
 //! Procedural macros for bevy-color-palettes
 
+mod derive;
+
 use proc_macro::TokenStream;
 use proc_macro_crate::{FoundCrate, crate_name};
 use proc_macro2::Span;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned};
+use std::collections::HashMap;
 use syn::braced;
 use syn::token::{Colon, Comma};
 use syn::{
-	Ident, LitStr, Result, parenthesized,
+	Attribute, Ident, LitStr, Result, Visibility, parenthesized,
 	parse::{Parse, ParseStream},
 	parse_macro_input,
 };
@@ -26,39 +29,132 @@ fn crate_root() -> proc_macro2::TokenStream {
 
 /// A color definition with a name and RGBA values
 struct ColorDef {
+	docs: Vec<Attribute>,
 	name: String,
+	name_span: Span,
 	r8: u8,
 	g8: u8,
 	b8: u8,
 	a8: u8,
+	/// The expression of a derived color, e.g. `lighten(primary, 0.1)`, until it is resolved
+	derived: Option<derive::ColorExpr>,
+}
+
+/// An alternative name for a color, e.g. `"dark_grey": alias("dark_gray")`
+struct AliasDef {
+	attrs: Vec<Attribute>,
+	name: String,
+	name_span: Span,
+	target: LitStr,
 }
 
-/// A palette definition with a name and a list of color definitions
+/// A palette definition with a name, a list of color definitions and their aliases
 struct PaletteDef {
+	attrs: Vec<Attribute>,
+	vis: Visibility,
 	name: Ident,
+	duplicate_colors: LintLevel,
 	colors: Vec<ColorDef>,
+	aliases: Vec<AliasDef>,
+}
+
+/// A single entry of a palette body, either a color or an alias
+enum EntryDef {
+	Color(ColorDef),
+	Alias(AliasDef),
+}
+
+/// How an opt-in lint such as `duplicate_colors` is reported
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LintLevel {
+	Allow,
+	Warn,
+	Deny,
 }
 
-/// Parse a color definition from a stream
-impl Parse for ColorDef {
+/// Parse a color or alias definition from a stream
+impl Parse for EntryDef {
 	fn parse(input: ParseStream) -> Result<Self> {
+		// Parse the outer attributes, e.g. `#[deprecated]` on an alias
+		let attrs = input.call(Attribute::parse_outer)?;
+
 		// Parse the color name as a string literal
 		let name_lit = input.parse::<LitStr>()?;
 		let name = name_lit.value();
+		let name_span = name_lit.span();
 
 		// Parse the colon
 		input.parse::<Colon>()?;
 
+		// Check if this is an alias of another color
+		if input.peek(Ident) && input.fork().parse::<Ident>()? == "alias" {
+			input.parse::<Ident>()?;
+			let content;
+			parenthesized!(content in input);
+			let target = content.parse::<LitStr>()?;
+			for attr in &attrs {
+				if !attr.path().is_ident("deprecated") && !attr.path().is_ident("doc") {
+					return Err(syn::Error::new_spanned(
+						attr,
+						"Only doc comments and `#[deprecated]` are supported on aliases.",
+					));
+				}
+			}
+			return Ok(EntryDef::Alias(AliasDef {
+				attrs,
+				name,
+				name_span,
+				target,
+			}));
+		}
+
+		if let Some(attr) = attrs.iter().find(|attr| !attr.path().is_ident("doc")) {
+			return Err(syn::Error::new_spanned(
+				attr,
+				if attr.path().is_ident("deprecated") {
+					"Only aliases can be deprecated; keep the old name as `alias(\"new_name\")`."
+				} else {
+					"Only doc comments are supported on colors."
+				},
+			));
+		}
+
+		let mut color = ColorDef::parse_value(name, name_span, input)?;
+		color.docs = attrs;
+		Ok(EntryDef::Color(color))
+	}
+}
+
+impl ColorDef {
+	/// Parse the value of a color definition, after its name and colon
+	fn parse_value(name: String, name_span: Span, input: ParseStream) -> Result<Self> {
+		// Check if this is derived from other colors, e.g. `mix(red, blue, 0.5)`
+		if input.peek(Ident) {
+			return Ok(ColorDef {
+				docs: Vec::new(),
+				name,
+				name_span,
+				r8: 0,
+				g8: 0,
+				b8: 0,
+				a8: 0,
+				derived: Some(input.parse()?),
+			});
+		}
+
 		// Check if the next token is a string
 		if input.peek(syn::LitStr) {
 			let lit = input.parse::<LitStr>()?;
 			let (r8, g8, b8, a8) = parse_hex_color(&lit.value(), lit.span())?;
 			Ok(ColorDef {
+				docs: Vec::new(),
 				name,
+				name_span,
 				r8,
 				g8,
 				b8,
 				a8,
+				derived: None,
 			})
 		} else {
 			// Otherwise, fall back to the version in weirdboi_bevy_colour (so we can merge upstream palettes with no issue)
@@ -103,11 +199,14 @@ impl Parse for ColorDef {
 			let a8 = 255;
 
 			Ok(ColorDef {
+				docs: Vec::new(),
 				name,
+				name_span,
 				r8,
 				g8,
 				b8,
 				a8,
+				derived: None,
 			})
 		}
 	}
@@ -173,6 +272,10 @@ fn parse_hex_color(html_hex_color_string: &str, span: Span) -> Result<(u8, u8, u
 /// Parse a palette definition from a stream
 impl Parse for PaletteDef {
 	fn parse(input: ParseStream) -> Result<Self> {
+		// Parse the outer attributes and visibility, e.g. `#[derive(Hash)] pub(crate)`
+		let attrs = input.call(Attribute::parse_outer)?;
+		let vis = input.parse::<Visibility>()?;
+
 		// Parse the palette name as an identifier
 		let name = input.parse::<Ident>()?;
 
@@ -180,10 +283,20 @@ impl Parse for PaletteDef {
 		let content;
 		braced!(content in input);
 
-		// Parse the color definitions
+		// Parse the inner attributes that configure lints, e.g. `#![deny(duplicate_colors)]`
+		let mut duplicate_colors = LintLevel::Allow;
+		for attr in content.call(Attribute::parse_inner)? {
+			duplicate_colors = parse_lint_attr(&attr)?;
+		}
+
+		// Parse the color and alias definitions
 		let mut colors = Vec::new();
+		let mut aliases = Vec::new();
 		while !content.is_empty() {
-			colors.push(content.parse::<ColorDef>()?);
+			match content.parse::<EntryDef>()? {
+				EntryDef::Color(color) => colors.push(color),
+				EntryDef::Alias(alias) => aliases.push(alias),
+			}
 
 			// Parse the comma if there is one and we're not at the end
 			if content.peek(Comma) {
@@ -193,36 +306,287 @@ impl Parse for PaletteDef {
 			}
 		}
 
-		Ok(PaletteDef { name, colors })
+		Ok(PaletteDef {
+			attrs,
+			vis,
+			name,
+			duplicate_colors,
+			colors,
+			aliases,
+		})
 	}
 }
 
-/// Convert a string to `UPPER_SNAKE_CASE`
-fn to_upper_snake_case(s: &str) -> String {
-	let mut result = String::new();
-	for (i, c) in s.chars().enumerate() {
-		if c.is_uppercase() && i > 0 && !s.chars().nth(i - 1).unwrap_or(' ').is_uppercase() {
-			result.push('_');
-		}
-		result.push(c.to_ascii_uppercase());
+/// Parse a `#![warn(duplicate_colors)]` style inner attribute
+fn parse_lint_attr(attr: &Attribute) -> Result<LintLevel> {
+	let level = if attr.path().is_ident("allow") {
+		LintLevel::Allow
+	} else if attr.path().is_ident("warn") {
+		LintLevel::Warn
+	} else if attr.path().is_ident("deny") {
+		LintLevel::Deny
+	} else {
+		return Err(syn::Error::new_spanned(
+			attr,
+			"Only `allow`, `warn` and `deny` inner attributes are supported.",
+		));
+	};
+
+	let lint = attr.parse_args::<Ident>()?;
+	if lint != "duplicate_colors" {
+		return Err(syn::Error::new_spanned(
+			lint,
+			"Unknown palette lint, expected `duplicate_colors`.",
+		));
 	}
-	result
+
+	Ok(level)
+}
+
+/// Method names generated for every palette, which colors can't reuse
+const RESERVED_METHODS: [&str; 4] = ["all", "get", "iter", "len"];
+
+/// The identifiers generated for a color name
+struct ColorIdents {
+	constant: Ident,
+	method: Ident,
+	variant: Ident,
 }
 
-/// Convert a string to `lower_snake_case`
-fn to_lower_snake_case(s: &str) -> String {
-	let mut result = String::new();
-	for (i, c) in s.chars().enumerate() {
-		if c.is_uppercase() {
-			if i > 0 && !s.chars().nth(i - 1).unwrap_or(' ').is_uppercase() {
-				result.push('_');
+/// Split a color name into lowercase words on `_`, `-`, ` ` and camelCase boundaries
+fn name_words(name: &str, span: Span) -> Result<Vec<String>> {
+	let mut words = Vec::new();
+	let mut word = String::new();
+	let mut prev: Option<char> = None;
+	for c in name.chars() {
+		if matches!(c, '_' | '-' | ' ') {
+			if !word.is_empty() {
+				words.push(std::mem::take(&mut word));
 			}
-			result.push(c.to_ascii_lowercase());
+		} else if c.is_ascii_alphanumeric() {
+			if c.is_ascii_uppercase()
+				&& !word.is_empty()
+				&& prev.is_some_and(|p| !p.is_ascii_uppercase())
+			{
+				words.push(std::mem::take(&mut word));
+			}
+			word.push(c.to_ascii_lowercase());
+		} else {
+			return Err(syn::Error::new(
+				span,
+				format!(
+					"Invalid character {c:?} in color name {name:?}; use ASCII letters, digits, `_`, `-` or spaces."
+				),
+			));
+		}
+		prev = Some(c);
+	}
+	if !word.is_empty() {
+		words.push(word);
+	}
+	if words.is_empty() {
+		return Err(syn::Error::new(
+			span,
+			"Color names must contain at least one letter or digit.",
+		));
+	}
+	Ok(words)
+}
+
+/// Derive the constant, method and enum variant identifiers for a color name, escaping names
+/// that start with a digit (`"8bit"` becomes `_8BIT`, `_8bit()` and `_8bit`) and keywords
+/// (`"type"` becomes `r#type()`)
+fn color_idents(name: &str, span: Span) -> Result<ColorIdents> {
+	let words = name_words(name, span)?;
+	let mut snake = words.join("_");
+	if snake.starts_with(|c: char| c.is_ascii_digit()) {
+		snake.insert(0, '_');
+	}
+
+	if RESERVED_METHODS.contains(&snake.as_str()) {
+		return Err(syn::Error::new(
+			span,
+			format!("Color name {name:?} collides with the generated `{snake}` method."),
+		));
+	}
+
+	let method_name = if syn::parse_str::<Ident>(&snake).is_ok() {
+		Ident::new(&snake, span)
+	} else if matches!(snake.as_str(), "self" | "super" | "crate") {
+		return Err(syn::Error::new(
+			span,
+			format!("Color name {name:?} can't be used as a method name."),
+		));
+	} else {
+		Ident::new_raw(&snake, span)
+	};
+
+	let mut pascal: String = words
+		.iter()
+		.map(|word| word[..1].to_ascii_uppercase() + &word[1..])
+		.collect();
+	if pascal.starts_with(|c: char| c.is_ascii_digit()) {
+		pascal.insert(0, '_');
+	}
+
+	Ok(ColorIdents {
+		constant: Ident::new(&snake.to_ascii_uppercase(), span),
+		method: method_name,
+		variant: Ident::new(&pascal, span),
+	})
+}
+
+/// The identifiers and resolved alias targets of a palette that passed its checks
+struct CheckedPalette {
+	color_idents: Vec<ColorIdents>,
+	alias_idents: Vec<ColorIdents>,
+	alias_targets: Vec<usize>,
+}
+
+/// Check for names that would be looked up as the same color and unresolved aliases
+fn check_palette(palette_def: &PaletteDef) -> Result<CheckedPalette> {
+	let mut errors: Option<syn::Error> = None;
+	let mut push_error = |error: syn::Error| match &mut errors {
+		Some(errors) => errors.combine(error),
+		None => errors = Some(error),
+	};
+
+	let names = palette_def
+		.colors
+		.iter()
+		.map(|color| (&color.name, color.name_span))
+		.chain(
+			palette_def
+				.aliases
+				.iter()
+				.map(|alias| (&alias.name, alias.name_span)),
+		);
+
+	let mut idents = Vec::new();
+	let mut seen_names: HashMap<String, &str> = HashMap::new();
+	for (name, span) in names {
+		match color_idents(name, span) {
+			Ok(color_idents) => idents.push(color_idents),
+			Err(error) => push_error(error),
+		}
+
+		let normalised = normalize_color_name(name);
+		if let Some(first) = seen_names.get(&normalised) {
+			push_error(syn::Error::new(
+				span,
+				format!(
+					"Color name {name:?} collides with {first:?}; both are looked up as {normalised:?}."
+				),
+			));
+		} else {
+			seen_names.insert(normalised, name);
+		}
+	}
+
+	let mut alias_targets = Vec::new();
+	for alias in &palette_def.aliases {
+		let target = normalize_color_name(&alias.target.value());
+		if let Some(index) = palette_def
+			.colors
+			.iter()
+			.position(|color| normalize_color_name(&color.name) == target)
+		{
+			alias_targets.push(index);
+		} else if palette_def
+			.aliases
+			.iter()
+			.any(|other| normalize_color_name(&other.name) == target)
+		{
+			push_error(syn::Error::new(
+				alias.target.span(),
+				"Aliases must refer to a color, not another alias.",
+			));
 		} else {
-			result.push(c);
+			push_error(syn::Error::new(
+				alias.target.span(),
+				format!("No color named {:?} in this palette.", alias.target.value()),
+			));
 		}
 	}
-	result
+
+	if let Some(errors) = errors {
+		return Err(errors);
+	}
+
+	let alias_idents = idents.split_off(palette_def.colors.len());
+	Ok(CheckedPalette {
+		color_idents: idents,
+		alias_idents,
+		alias_targets,
+	})
+}
+
+/// Pairs of each color with the first earlier color that has the same value
+fn duplicate_colors(colors: &[ColorDef]) -> Vec<(&ColorDef, &ColorDef)> {
+	let mut seen: HashMap<[u8; 4], &ColorDef> = HashMap::new();
+	let mut duplicates = Vec::new();
+	for color in colors {
+		let rgba = [color.r8, color.g8, color.b8, color.a8];
+		if let Some(first) = seen.get(&rgba) {
+			duplicates.push((color, *first));
+		} else {
+			seen.insert(rgba, color);
+		}
+	}
+	duplicates
+}
+
+fn duplicate_color_message(color: &ColorDef, first: &ColorDef) -> String {
+	format!(
+		"duplicate_colors: {:?} has the same value as {:?} (#{:02x}{:02x}{:02x}{:02x}).",
+		color.name, first.name, color.r8, color.g8, color.b8, color.a8
+	)
+}
+
+/// Apply the `duplicate_colors` lint, returning an error for each duplicate when denied or the
+/// warnings to emit when warned. Derived colors must be resolved first, since they all hold a
+/// placeholder value until then.
+fn check_duplicate_colors(palette_def: &PaletteDef) -> Result<Vec<proc_macro2::TokenStream>> {
+	match palette_def.duplicate_colors {
+		LintLevel::Allow => Ok(Vec::new()),
+		LintLevel::Warn => Ok(duplicate_color_warnings(&palette_def.colors)),
+		LintLevel::Deny => {
+			let errors = duplicate_colors(&palette_def.colors)
+				.into_iter()
+				.map(|(color, first)| {
+					syn::Error::new(color.name_span, duplicate_color_message(color, first))
+				})
+				.reduce(|mut errors, error| {
+					errors.combine(error);
+					errors
+				});
+			match errors {
+				Some(errors) => Err(errors),
+				None => Ok(Vec::new()),
+			}
+		}
+	}
+}
+
+/// Emit a warning for each duplicate color.
+///
+/// Proc macros can't emit warnings on stable, so this uses a deprecated constant spanned to the
+/// duplicate entry, which the compiler reports as a warning at that location.
+fn duplicate_color_warnings(colors: &[ColorDef]) -> Vec<proc_macro2::TokenStream> {
+	duplicate_colors(colors)
+		.into_iter()
+		.map(|(color, first)| {
+			let note = duplicate_color_message(color, first);
+			quote_spanned! {color.name_span=>
+				const _: () = {
+					#[deprecated(note = #note)]
+					#[allow(non_upper_case_globals)]
+					const duplicate_colors: () = ();
+					duplicate_colors
+				};
+			}
+		})
+		.collect()
 }
 
 /// Generate a palette struct and implementation
@@ -238,140 +602,325 @@ fn to_lower_snake_case(s: &str) -> String {
 /// });
 /// ```
 ///
-/// This will generate:
+/// This will generate (abridged):
 ///
 /// ```ignore
 /// pub struct MyPalette;
 ///
 /// impl MyPalette {
-///     /// RED; <div style="background-color: rgb(100% 0% 0% 100%); height: 20px"></div>
 ///     pub const RED: Color = Color::new(255, 0, 0, 255);
-///     /// GREEN; <div style="background-color: rgb(0% 100% 0% 100%); height: 20px"></div>
 ///     pub const GREEN: Color = Color::new(0, 255, 0, 255);
-///     /// BLUE; <div style="background-color: rgb(0% 0% 100% 100%); height: 20px"></div>
 ///     pub const BLUE: Color = Color::new(0, 0, 255, 255);
 ///
-///     pub fn red(&self) -> Color { Self::RED }
-///     pub fn green(&self) -> Color { Self::GREEN }
-///     pub fn blue(&self) -> Color { Self::BLUE }
+///     pub const fn red() -> Color { Self::RED }
+///     pub const fn green() -> Color { Self::GREEN }
+///     pub const fn blue() -> Color { Self::BLUE }
+///
+///     // all(), len(), iter() and get(), backed by the entry table below
 /// }
 ///
 /// impl Palette for MyPalette {
-///     // Implementation of Palette trait
+///     const NAME: &'static str = "MyPalette";
+///     const ENTRIES: &'static [Entry] = &[
+///         Entry::new("red", Self::RED),
+///         Entry::new("green", Self::GREEN),
+///         Entry::new("blue", Self::BLUE),
+///     ];
+///     const LOOKUP: &'static [(&'static str, usize)] = &[("blue", 2), ("green", 1), ("red", 0)];
 /// }
 /// ```
+///
+/// The color previews in the generated rustdoc are only emitted with the `doc-previews` feature.
+///
+/// # Visibility and attributes
+///
+/// Palettes are `pub` unless given a visibility, e.g. `pub(crate)` or `pub(self)`, which also
+/// applies to the generated color enum. Outer attributes go before the visibility: doc comments
+/// are prepended to the generated docs, `#[cfg]` applies to every generated item, and any other
+/// attribute, such as `#[derive(Hash)]`, is added to the palette struct. Doc comments on a color
+/// are added to its constant and method, and its enum variant links to the constant.
+///
+/// ```ignore
+/// palette!(
+///     /// Colors for the debug overlay.
+///     #[derive(Default)]
+///     pub(crate) DebugPalette {
+///         /// Used for colliders.
+///         "collider": "#00ff00",
+///     }
+/// );
+/// ```
+///
+/// # Names
+///
+/// Color names may contain ASCII letters, digits, `_`, `-` and spaces. Names starting with a
+/// digit are escaped with a leading underscore (`"8bit"` generates `_8BIT` and `_8bit()`) and
+/// keywords become raw identifiers (`"type"` generates `r#type()`). Two names that are looked up
+/// as the same color, such as `"dark_gray"` and `"dark-gray"`, are an error.
+///
+/// # Aliases
+///
+/// An entry can be another name for an existing color. Aliases get a constant and a method and
+/// are found by `get`, but are not repeated by `all()`, `iter()` or `len()`. Mark an alias
+/// `#[deprecated]` to rename a color without breaking code that uses the old name:
+///
+/// ```ignore
+/// palette!(MyPalette {
+///     "dark_gray": "#333333",
+///     "dark_grey": alias("dark_gray"),
+///     #[deprecated(note = "renamed to `dark_gray`")]
+///     "charcoal": alias("dark_gray"),
+/// });
+/// ```
+///
+/// # Derived colors
+///
+/// An entry can be computed from other entries of the same palette at compile time. The math is
+/// done in Oklab, so `lighten` and `mix` look even across hues, and results outside sRGB keep
+/// their lightness and hue while losing chroma. Entries are referred to by name, as an identifier
+/// or a string, in any order and through aliases; arguments may also be hex strings.
+///
+/// - `lighten(color, amount)` and `darken(color, amount)` shift Oklab lightness, from 0 to 1.
+/// - `saturate(color, amount)` and `desaturate(color, amount)` scale chroma by `1 ± amount`.
+/// - `rotate_hue(color, degrees)` rotates the Oklab hue.
+/// - `with_alpha(color, alpha)` replaces the alpha, from 0 to 1.
+/// - `mix(a, b, t)` interpolates from `a` to `b`, alpha included.
+///
+/// ```ignore
+/// palette!(Theme {
+///     "primary": "#3b82f6",
+///     "hover": lighten(primary, 0.1),
+///     "overlay": with_alpha("#000000", 0.5),
+///     "muted": mix(primary, "#808080", 0.5),
+///     "accent": rotate_hue(desaturate(primary, 0.2), 180),
+/// });
+/// ```
+///
+/// # Lints
+///
+/// Duplicate color values are allowed by default. Add `#![warn(duplicate_colors)]` or
+/// `#![deny(duplicate_colors)]` at the start of the palette body to report them:
+///
+/// ```ignore
+/// palette!(MyPalette {
+///     #![deny(duplicate_colors)]
+///     "red": "#ff0000",
+///     "also_red": "#ff0000", // error: has the same value as "red"
+/// });
+/// ```
 #[allow(clippy::too_many_lines)]
 #[proc_macro]
 pub fn palette(input: TokenStream) -> TokenStream {
 	// Parse the input
-	let palette_def = parse_macro_input!(input as PaletteDef);
+	let mut palette_def = parse_macro_input!(input as PaletteDef);
+	let checked = match check_palette(&palette_def) {
+		Ok(checked) => checked,
+		Err(errors) => return errors.to_compile_error().into(),
+	};
+	if let Err(error) = derive::resolve_derived(&mut palette_def) {
+		return error.to_compile_error().into();
+	}
+	let warnings = match check_duplicate_colors(&palette_def) {
+		Ok(warnings) => warnings,
+		Err(errors) => return errors.to_compile_error().into(),
+	};
 
 	// Generate the struct definition
 	let palette_name = &palette_def.name;
+	let palette_name_str = palette_name.to_string();
 	let crate_root = crate_root();
 	let crate_color = quote! { #crate_root::color::Color };
+	let crate_palette = quote! { #crate_root::palette };
 
-	// Generate the color constants and methods
+	// Generate the entry table, plus a constant and a method per entry
+	let mut entries = Vec::new();
 	let mut const_defs = Vec::new();
 	let mut method_defs = Vec::new();
-	let mut get_color_match_arms = Vec::new();
-	let mut color_values = Vec::new();
+	let mut lookup_entries = Vec::new();
+	let mut variants = Vec::new();
 	let mut doc_grid_entry = Vec::new();
-	let mut color_rgba = Vec::new();
 
-	for color in &palette_def.colors {
+	for (index, (color, color_idents)) in palette_def
+		.colors
+		.iter()
+		.zip(&checked.color_idents)
+		.enumerate()
+	{
 		let color_name = &color.name;
-		let normalised = normalize_color_name(color_name);
-		let const_name = Ident::new(&to_upper_snake_case(color_name), Span::call_site());
-		let method_name = format_ident!("{}", to_lower_snake_case(color_name));
+		let const_name = &color_idents.constant;
+		let method_name = &color_idents.method;
 
 		let r8 = color.r8;
 		let g8 = color.g8;
 		let b8 = color.b8;
 		let a8 = color.a8;
 
-		let current_rgba = format!(
-			"rgba({:.0}%, {:.0}%, {:.0}%, {:.2})",
-			f32::from(r8) * (100.0 / 255.0),
-			f32::from(g8) * (100.0 / 255.0),
-			f32::from(b8) * (100.0 / 255.0),
-			f32::from(a8) / 255.0,
-		);
-
-		let rustdoc =
-			format!(r#"<div style="background-color: {current_rgba}; height: 20px"></div>"#,);
+		entries.push(quote! {
+			#crate_palette::Entry::new(#color_name, Self::#const_name),
+		});
 
-		let funcdoc =
-			format!(r"Returns the value of [{palette_name}::{const_name}]<br/>{rustdoc}",);
-		color_rgba.push(current_rgba);
+		// User doc comments go first, followed by the generated docs as their own paragraph
+		let docs = &color.docs;
+		let separator = (!docs.is_empty()).then(|| quote! { #[doc = ""] });
+		let preview = cfg!(feature = "doc-previews").then(|| {
+			format!(
+				r#"<div style="background-color: {}; height: 20px"></div>"#,
+				css_rgba(color)
+			)
+		});
+		let const_doc = match (&preview, docs.is_empty()) {
+			(Some(preview), _) => quote! { #(#docs)* #separator #[doc = #preview] },
+			(None, true) => {
+				let fallback = format!("The `{color_name}` color.");
+				quote! { #[doc = #fallback] }
+			}
+			(None, false) => quote! { #(#docs)* },
+		};
+		let returns = match &preview {
+			Some(preview) => {
+				format!("Returns the value of [{palette_name}::{const_name}]<br/>{preview}")
+			}
+			None => format!("Returns the value of [{palette_name}::{const_name}]"),
+		};
+		let method_doc = quote! { #(#docs)* #separator #[doc = #returns] };
 
-		// Add the constant definition
+		// Each value is written once, as a constant; the entry table and methods refer to it
 		const_defs.push(quote! {
-			#[doc = #rustdoc]
+			#const_doc
 			pub const #const_name: #crate_color = #crate_color::new(#r8, #g8, #b8, #a8);
 		});
-
-		// Add the method definition (static, no &self)
 		method_defs.push(quote! {
-			#[doc = #funcdoc]
+			#method_doc
 			pub const fn #method_name() -> #crate_color {
 				Self::#const_name
 			}
 		});
 
-		// Add the match arm for get_color
-		get_color_match_arms.push(quote! {
-			#normalised => Some(Self::#const_name),
+		lookup_entries.push((normalize_color_name(color_name), index));
+
+		// Variants link to the constant rather than repeating its docs and preview
+		let variant_name = &color_idents.variant;
+		let variant_doc =
+			format!("The `{color_name}` color, see [`{palette_name}::{const_name}`].");
+		variants.push(quote! {
+			#[doc = #variant_doc]
+			#variant_name,
 		});
 
-		// Add the color value for the iterator
-		color_values.push(quote! {
-			Self::#const_name,
+		if cfg!(feature = "doc-previews") {
+			doc_grid_entry.push(format!(
+				r#"<div style="background-color: {}; width: 20px; height: 20px;"></div>"#,
+				css_rgba(color)
+			));
+		}
+	}
+
+	// Aliases get a constant, a method and a lookup row, but no entry of their own
+	for ((alias, alias_idents), &target) in palette_def
+		.aliases
+		.iter()
+		.zip(&checked.alias_idents)
+		.zip(&checked.alias_targets)
+	{
+		let attrs = &alias.attrs;
+		let separator = alias
+			.attrs
+			.iter()
+			.any(|attr| attr.path().is_ident("doc"))
+			.then(|| quote! { #[doc = ""] });
+		let const_name = &alias_idents.constant;
+		let method_name = &alias_idents.method;
+		let target_const = &checked.color_idents[target].constant;
+
+		let const_doc = format!("Alias of [{palette_name}::{target_const}].");
+		let method_doc = format!("Returns the value of [{palette_name}::{target_const}].");
+		const_defs.push(quote! {
+			#(#attrs)*
+			#separator
+			#[doc = #const_doc]
+			pub const #const_name: #crate_color = Self::#target_const;
+		});
+		method_defs.push(quote! {
+			#(#attrs)*
+			#separator
+			#[doc = #method_doc]
+			pub const fn #method_name() -> #crate_color {
+				Self::#target_const
+			}
 		});
 
-		doc_grid_entry.push(format!(
-			r#"<div style="background-color: rgba({:.0}% {:.0}% {:.0}% {:.2}); width: 20px; height: 20px;"></div>"#,
-			f32::from(r8) * (100.0 / 255.0),
-			f32::from(g8) * (100.0 / 255.0),
-			f32::from(b8) * (100.0 / 255.0),
-			f32::from(a8) / 255.0,
-		));
+		lookup_entries.push((normalize_color_name(&alias.name), target));
 	}
 
+	// Sort the lookup table by normalized name so get can binary search it
+	lookup_entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+	let lookup_rows = lookup_entries
+		.iter()
+		.map(|(normalised, index)| quote! { (#normalised, #index), });
+
 	// Get the number of colors
 	let num_colors = palette_def.colors.len();
 	let num_colors_lit = proc_macro2::Literal::usize_unsuffixed(num_colors);
 	let iter_type = quote! { ::core::array::IntoIter<#crate_color, #num_colors_lit> };
 
-	let root_doc = format!(
-		r#"<span>The {palette_name} palette, containing {num_colors} colors.</span> <br />
+	let root_doc = if cfg!(feature = "doc-previews") {
+		format!(
+			r#"<span>The {palette_name} palette, containing {num_colors} colors.</span> <br />
 		<div style="display: grid; grid-template-columns: repeat(8, 20px); grid-auto-rows: 20px;">{}</div>"#,
-		doc_grid_entry.join("\n")
+			doc_grid_entry.join("\n")
+		)
+	} else {
+		format!("The {palette_name} palette, containing {num_colors} colors.")
+	};
+
+	// `#[cfg]` applies to every generated item, doc comments and other attributes to the struct
+	let (cfgs, attrs): (Vec<_>, Vec<_>) = palette_def
+		.attrs
+		.iter()
+		.partition(|attr| attr.path().is_ident("cfg"));
+	let (docs, other_attrs): (Vec<_>, Vec<_>) = attrs
+		.into_iter()
+		.partition(|attr| attr.path().is_ident("doc"));
+	let doc_separator = (!docs.is_empty()).then(|| quote! { #[doc = ""] });
+
+	// Palettes are public unless a visibility is given, e.g. `pub(crate)`
+	let vis = match &palette_def.vis {
+		Visibility::Inherited => quote! { pub },
+		vis => quote! { #vis },
+	};
+
+	// The enum naming each entry of the palette, e.g. `Dawnbringer16Color::MossGreen`
+	let enum_name = format_ident!("{palette_name}Color");
+	let enum_doc = format!(
+		"Names an entry of the [{palette_name}] palette, so components and save files can refer to a palette color by identity rather than by value."
 	);
+	let variant_names = checked.color_idents.iter().map(|idents| &idents.variant);
 
 	// Generate the final code
 	let expanded = quote! {
+		#(#cfgs)*
+		const _: () = {
+			#(#warnings)*
+		};
+
+		#(#docs)*
+		#doc_separator
 		#[doc = #root_doc]
+		#(#other_attrs)*
+		#(#cfgs)*
 		#[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy)]
-		pub struct #palette_name;
+		#vis struct #palette_name;
 
+		#(#cfgs)*
+		#[allow(dead_code)]
 		impl #palette_name {
 			#(#const_defs)*
 
 			#(#method_defs)*
 
-			// Helper function to normalize color names for case-insensitive and format-agnostic comparison
-			#[doc(hidden)]
-			fn normalize_color_name(s: &str) -> String {
-				s.chars()
-					.filter(|c| c.is_alphanumeric())
-					.map(|c| c.to_ascii_lowercase())
-					.collect()
-			}
-
 			/// Returns all colors in the palette as a fixed-size array
 			pub const fn all() -> [#crate_color; #num_colors_lit] {
-				[#(#color_values)*]
+				#crate_palette::colors(<Self as #crate_palette::Palette>::ENTRIES)
 			}
 
 			/// Returns the number of colours in the palette
@@ -384,16 +933,20 @@ pub fn palette(input: TokenStream) -> TokenStream {
 				Self::all().into_iter()
 			}
 
-			/// Returns a color by name, if it exists in the palette
+			/// Returns a color by case and format insensitive name, if it exists in the palette
 			pub fn get(name: &str) -> Option<#crate_color> {
-				let name = Self::normalize_color_name(name);
-				match name.as_str() {
-					#(#get_color_match_arms)*
-					_ => None,
-				}
+				<Self as #crate_palette::Palette>::entry(name).map(|entry| entry.color)
 			}
 		}
 
+		#(#cfgs)*
+		impl #crate_palette::Palette for #palette_name {
+			const NAME: &'static str = #palette_name_str;
+			const ENTRIES: &'static [#crate_palette::Entry] = &[#(#entries)*];
+			const LOOKUP: &'static [(&'static str, usize)] = &[#(#lookup_rows)*];
+		}
+
+		#(#cfgs)*
 		impl IntoIterator for #palette_name {
 			type Item = #crate_color;
 			type IntoIter = #iter_type;
@@ -403,6 +956,7 @@ pub fn palette(input: TokenStream) -> TokenStream {
 			}
 		}
 
+		#(#cfgs)*
 		impl<'a> IntoIterator for &'a #palette_name {
 			type Item = #crate_color;
 			type IntoIter = #iter_type;
@@ -411,15 +965,261 @@ pub fn palette(input: TokenStream) -> TokenStream {
 				#palette_name::all().into_iter()
 			}
 		}
+
+		#(#cfgs)*
+		#crate_root::__palette_enum_derive! {
+			#[doc = #enum_doc]
+			#[derive(
+				::core::fmt::Debug,
+				::core::clone::Clone,
+				::core::marker::Copy,
+				::core::cmp::PartialEq,
+				::core::cmp::Eq,
+				::core::cmp::PartialOrd,
+				::core::cmp::Ord,
+				::core::hash::Hash,
+			)]
+			#[allow(dead_code)]
+			#vis enum #enum_name {
+				#(#variants)*
+			}
+		}
+
+		#(#cfgs)*
+		#[allow(dead_code)]
+		impl #enum_name {
+			/// Every color of the palette, in declaration order
+			pub const ALL: [Self; #num_colors_lit] = [#(Self::#variant_names),*];
+
+			/// Returns the position of this color in the palette
+			pub const fn index(self) -> usize {
+				self as usize
+			}
+
+			/// Returns the color at `index` in the palette, if there is one
+			pub const fn from_index(index: usize) -> Option<Self> {
+				if index < #num_colors_lit {
+					Some(Self::ALL[index])
+				} else {
+					None
+				}
+			}
+
+			/// Returns the name of this color, as written in the palette
+			pub const fn name(self) -> &'static str {
+				<#palette_name as #crate_palette::Palette>::ENTRIES[self as usize].name
+			}
+
+			/// Returns the value of this color
+			pub const fn color(self) -> #crate_color {
+				<#palette_name as #crate_palette::Palette>::ENTRIES[self as usize].color
+			}
+		}
+
+		#(#cfgs)*
+		impl ::core::convert::From<#enum_name> for #crate_color {
+			fn from(color: #enum_name) -> Self {
+				color.color()
+			}
+		}
+
+		#(#cfgs)*
+		impl ::core::fmt::Display for #enum_name {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				f.write_str(self.name())
+			}
+		}
+
+		#(#cfgs)*
+		impl ::core::str::FromStr for #enum_name {
+			type Err = #crate_palette::UnknownColorName;
+
+			/// Parses a case and format insensitive color name, including aliases
+			fn from_str(name: &str) -> ::core::result::Result<Self, Self::Err> {
+				#crate_palette::lookup(<#palette_name as #crate_palette::Palette>::LOOKUP, name)
+					.map(|&index| Self::ALL[index])
+					.ok_or(#crate_palette::UnknownColorName)
+			}
+		}
+
+		#(#cfgs)*
+		#crate_root::__palette_enum_serde!(#enum_name);
 	};
 
 	// Return the generated code
 	expanded.into()
 }
 
+/// Format a color as a CSS `rgba()` value for the rustdoc previews
+fn css_rgba(color: &ColorDef) -> String {
+	format!(
+		"rgba({:.0}%, {:.0}%, {:.0}%, {:.2})",
+		f32::from(color.r8) * (100.0 / 255.0),
+		f32::from(color.g8) * (100.0 / 255.0),
+		f32::from(color.b8) * (100.0 / 255.0),
+		f32::from(color.a8) / 255.0,
+	)
+}
+
+/// Normalize a color name the same way as `palette::normalized_chars` in the main crate
 fn normalize_color_name(s: &str) -> String {
 	s.chars()
 		.filter(|c| c.is_alphanumeric())
 		.map(|c| c.to_ascii_lowercase())
 		.collect()
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Parse and check a palette like `palette!` does, returning every error message
+	fn errors(input: &str) -> Vec<String> {
+		let check = |mut def: PaletteDef| {
+			check_palette(&def)?;
+			derive::resolve_derived(&mut def)?;
+			check_duplicate_colors(&def)
+		};
+		match syn::parse_str::<PaletteDef>(input).and_then(check) {
+			Ok(_) => Vec::new(),
+			Err(errors) => errors.into_iter().map(|error| error.to_string()).collect(),
+		}
+	}
+
+	fn assert_error(input: &str, expected: &str) {
+		let errors = errors(input);
+		assert!(
+			errors.iter().any(|error| error.contains(expected)),
+			"expected an error containing {expected:?}, got {errors:?}"
+		);
+	}
+
+	#[test]
+	fn valid_palette() {
+		assert_eq!(
+			errors(r##"Test { "red": "#ff0000", "dark_red": "#800000", "8bit": "#123456" }"##),
+			Vec::<String>::new()
+		);
+	}
+
+	#[test]
+	fn normalized_name_collision() {
+		assert_error(
+			r##"Test { "dark_gray": "#444444", "darkGray": "#555555" }"##,
+			r#"Color name "darkGray" collides with "dark_gray"; both are looked up as "darkgray"."#,
+		);
+	}
+
+	#[test]
+	fn reserved_method_names() {
+		for name in RESERVED_METHODS {
+			assert_error(
+				&format!(r##"Test {{ "{name}": "#ffffff" }}"##),
+				&format!("collides with the generated `{name}` method"),
+			);
+		}
+	}
+
+	#[test]
+	fn invalid_characters() {
+		assert_error(
+			r##"Test { "red!": "#ff0000" }"##,
+			r#"Invalid character '!' in color name "red!""#,
+		);
+		assert_error(
+			r##"Test { "--": "#ff0000" }"##,
+			"Color names must contain at least one letter or digit.",
+		);
+	}
+
+	#[test]
+	fn path_keywords() {
+		for name in ["self", "super", "crate"] {
+			assert_error(
+				&format!(r##"Test {{ "{name}": "#ffffff" }}"##),
+				"can't be used as a method name",
+			);
+		}
+	}
+
+	#[test]
+	fn keywords_are_escaped() {
+		let idents = color_idents("type", Span::call_site()).unwrap();
+		assert_eq!(idents.method.to_string(), "r#type");
+		assert_eq!(idents.constant.to_string(), "TYPE");
+	}
+
+	#[test]
+	fn unknown_lints() {
+		assert_error(
+			r##"Test { #![deny(duplicate_colours)] "red": "#ff0000" }"##,
+			"Unknown palette lint, expected `duplicate_colors`.",
+		);
+		assert_error(
+			r##"Test { #![forbid(duplicate_colors)] "red": "#ff0000" }"##,
+			"Only `allow`, `warn` and `deny` inner attributes are supported.",
+		);
+	}
+
+	#[test]
+	fn deny_duplicate_colors() {
+		let duplicates = r##""red": "#ff0000", "also_red": "#ff0000""##;
+		assert_eq!(
+			errors(&format!("Test {{ {duplicates} }}")),
+			Vec::<String>::new()
+		);
+		assert_eq!(
+			errors(&format!(
+				"Test {{ #![warn(duplicate_colors)] {duplicates} }}"
+			)),
+			Vec::<String>::new()
+		);
+		assert_error(
+			&format!("Test {{ #![deny(duplicate_colors)] {duplicates} }}"),
+			r#"duplicate_colors: "also_red" has the same value as "red" (#ff0000ff)."#,
+		);
+	}
+
+	#[test]
+	fn deny_duplicate_colors_after_deriving() {
+		assert_eq!(
+			errors(
+				r##"Test {
+					#![deny(duplicate_colors)]
+					"primary": "#3b82f6",
+					"hover": lighten(primary, 0.1),
+					"pressed": darken(primary, 0.1),
+				}"##
+			),
+			Vec::<String>::new()
+		);
+		assert_error(
+			r##"Test {
+				#![deny(duplicate_colors)]
+				"primary": "#3b82f6",
+				"same": rotate_hue(primary, 360),
+			}"##,
+			r#"duplicate_colors: "same" has the same value as "primary" (#3b82f6ff)."#,
+		);
+	}
+
+	#[test]
+	fn unknown_color_functions() {
+		assert_error(
+			r##"Test { "red": "#ff0000", "pink": blend(red, 0.5) }"##,
+			"Unknown color function `blend`; expected `lighten`, `darken`, `saturate`, `desaturate`, `rotate_hue`, `with_alpha` or `mix`.",
+		);
+		assert_error(
+			r##"Test { "red": "#ff0000", "pink": lighten(alias("red"), 0.5) }"##,
+			"`alias` is only allowed as the whole value of an entry",
+		);
+	}
+
+	#[test]
+	fn every_error_is_reported() {
+		assert_eq!(
+			errors(r##"Test { "len": "#000000", "red": "#ff0000", "RED": "#ff0000" }"##).len(),
+			2
+		);
+	}
+}
@@ -1,5 +1,6 @@
 use macros::palette;
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Aap64 {
 	"color_060608": "#060608ff",
 	"color_141013": "#141013ff",
@@ -67,6 +68,7 @@ palette!(Aap64 {
 	"color_423934": "#423934ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(AapMicro12 {
 	"rich_black_fogra39": "#040303ff",
 	"eerie_black": "#1c1618ff",
@@ -82,6 +84,7 @@ palette!(AapMicro12 {
 	"ube": "#8a8fc4ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(AapRadiantxv {
 	"color_070505": "#070505ff",
 	"color_211919": "#211919ff",
@@ -100,6 +103,7 @@ palette!(AapRadiantxv {
 	"color_945b28": "#945b28ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(AapSplendor128 {
 	"rich_black_fogra39": "#050403ff",
 	"smoky_black": "#0e0c0cff",
@@ -231,6 +235,7 @@ palette!(AapSplendor128 {
 	"raisin_black": "#2a1e23ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Simplejpc16 {
 	"rich_black_fogra39": "#050403ff",
 	"raisin_black": "#221f31ff",
@@ -250,6 +255,7 @@ palette!(Simplejpc16 {
 	"dark_salmon": "#ea9182ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(A64 {
 	"color_000000ff": "#000000ff",
 	"color_313a91ff": "#313a91ff",
@@ -269,6 +275,7 @@ palette!(A64 {
 	"color_ede6c8ff": "#ede6c8ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Arne16 {
 	"black_void": "#000000ff",
 	"grey_gray_ash": "#9d9d9dff",
@@ -288,6 +295,7 @@ palette!(Arne16 {
 	"lblue_lightblue_cloudblue": "#b2dcefff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Arne32 {
 	"color_000000ff": "#000000ff",
 	"color_9d9d9dff": "#9d9d9dff",
@@ -323,6 +331,7 @@ palette!(Arne32 {
 	"color_f4b990ff": "#f4b990ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(CgArne {
 	"color_000000ff": "#000000ff",
 	"color_2234d1ff": "#2234d1ff",
@@ -342,6 +351,7 @@ palette!(CgArne {
 	"color_ffffffff": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(CopperTech {
 	"color_262144ff": "#262144ff",
 	"color_1651ddff": "#1651ddff",
@@ -361,6 +371,7 @@ palette!(CopperTech {
 	"color_000000ff": "#000000ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(CpcBoy {
 	"color_000000ff": "#000000ff",
 	"color_1b1b76ff": "#1b1b76ff",
@@ -396,6 +407,7 @@ palette!(CpcBoy {
 	"color_293941ff": "#293941ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(ErogeCopper {
 	"color_7d3840ff": "#7d3840ff",
 	"color_0d080dff": "#0d080dff",
@@ -415,6 +427,7 @@ palette!(ErogeCopper {
 	"color_4f2b24ff": "#4f2b24ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Jmp {
 	"color_000000ff": "#000000ff",
 	"color_191028ff": "#191028ff",
@@ -434,6 +447,7 @@ palette!(Jmp {
 	"color_f5f4ebff": "#f5f4ebff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Psygnosia {
 	"color_a2324eff": "#a2324eff",
 	"color_443f41ff": "#443f41ff",
@@ -453,6 +467,7 @@ palette!(Psygnosia {
 	"color_000000ff": "#000000ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Matriax8c {
 	"color_f0f0dcff": "#f0f0dcff",
 	"color_fac800ff": "#fac800ff",
@@ -464,6 +479,7 @@ palette!(Matriax8c {
 	"color_101820ff": "#101820ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Db16 {
 	"color_140c1cff": "#140c1cff",
 	"color_442434ff": "#442434ff",
@@ -483,6 +499,7 @@ palette!(Db16 {
 	"color_deeed6ff": "#deeed6ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Db32 {
 	"color_000000ff": "#000000ff",
 	"color_222034ff": "#222034ff",
@@ -518,6 +535,7 @@ palette!(Db32 {
 	"color_8a6f30ff": "#8a6f30ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Arq16 {
 	"color_ffffffff": "#ffffffff",
 	"color_ffd19dff": "#ffd19dff",
@@ -537,6 +555,7 @@ palette!(Arq16 {
 	"color_5ae150ff": "#5ae150ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Arq4 {
 	"color_ffffffff": "#ffffffff",
 	"color_6772a9ff": "#6772a9ff",
@@ -544,6 +563,7 @@ palette!(Arq4 {
 	"color_000000ff": "#000000ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Edg16 {
 	"birch": "#e4a672ff",
 	"oak": "#b86f50ff",
@@ -563,6 +583,7 @@ palette!(Edg16 {
 	"archaeon": "#0484d1ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Edg32 {
 	"tetanus": "#be4a2fff",
 	"rust": "#d87644ff",
@@ -598,6 +619,7 @@ palette!(Edg32 {
 	"shadeskin": "#c28569ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Edg8 {
 	"color_fdfdf8ff": "#fdfdf8ff",
 	"color_d32734ff": "#d32734ff",
@@ -609,6 +631,7 @@ palette!(Edg8 {
 	"color_1b1c33ff": "#1b1c33ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(En4 {
 	"color_fbf7f3ff": "#fbf7f3ff",
 	"color_e5b083ff": "#e5b083ff",
@@ -616,6 +639,7 @@ palette!(En4 {
 	"color_20283dff": "#20283dff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Enos16 {
 	"color_fafafaff": "#fafafaff",
 	"color_d4d4d4ff": "#d4d4d4ff",
@@ -635,6 +659,7 @@ palette!(Enos16 {
 	"color_948e82ff": "#948e82ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Hept32 {
 	"color_000000ff": "#000000ff",
 	"color_180d2fff": "#180d2fff",
@@ -670,6 +695,7 @@ palette!(Hept32 {
 	"color_3d083bff": "#3d083bff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(AppleIi {
 	"black": "#000000ff",
 	"magenta": "#6c2940ff",
@@ -689,6 +715,7 @@ palette!(AppleIi {
 	"white": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Atari2600Ntsc {
 	"color_000000ff": "#000000ff",
 	"color_444400ff": "#444400ff",
@@ -821,6 +848,7 @@ palette!(Atari2600Ntsc {
 	"color_ffffffff": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Atari2600Pal {
 	"color_000000ff": "#000000ff",
 	"color_805800ff": "#805800ff",
@@ -928,6 +956,7 @@ palette!(Atari2600Pal {
 	"color_b0b0fcff": "#b0b0fcff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Cga {
 	"black": "#000000ff",
 	"blue": "#0000aaff",
@@ -947,6 +976,7 @@ palette!(Cga {
 	"white": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Cga0 {
 	"black": "#000000ff",
 	"green": "#00aa00ff",
@@ -954,6 +984,7 @@ palette!(Cga0 {
 	"brown": "#aa5500ff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Cga0hi {
 	"black": "#000000ff",
 	"light_green": "#55ff55ff",
@@ -961,6 +992,7 @@ palette!(Cga0hi {
 	"yellow": "#ffff55ff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Cga1 {
 	"black": "#000000ff",
 	"cyan": "#00aaaaff",
@@ -968,6 +1000,7 @@ palette!(Cga1 {
 	"light_gray": "#aaaaaaff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Cga1hi {
 	"black": "#000000ff",
 	"light_cyan": "#55ffffff",
@@ -975,6 +1008,7 @@ palette!(Cga1hi {
 	"white": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Cga3rd {
 	"black": "#000000ff",
 	"cyan": "#00aaaaff",
@@ -982,6 +1016,7 @@ palette!(Cga3rd {
 	"light_gray": "#aaaaaaff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Cga3rdhi {
 	"black": "#000000ff",
 	"light_cyan": "#55ffffff",
@@ -989,6 +1024,7 @@ palette!(Cga3rdhi {
 	"white": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(CommodorePlus4 {
 	"color_000000ff": "#000000ff",
 	"color_202020ff": "#202020ff",
@@ -1111,6 +1147,7 @@ palette!(CommodorePlus4 {
 	"color_c2ffd9ff": "#c2ffd9ff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(CommodoreVic20 {
 	"color_000000ff": "#000000ff",
 	"color_ffffffff": "#ffffffff",
@@ -1130,6 +1167,7 @@ palette!(CommodoreVic20 {
 	"color_ffffb2ff": "#ffffb2ff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Commodore64 {
 	"color_000000ff": "#000000ff",
 	"color_ffffffff": "#ffffffff",
@@ -1149,6 +1187,7 @@ palette!(Commodore64 {
 	"color_9f9f9fff": "#9f9f9fff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Cpc {
 	"black": "#000000ff",
 	"blue": "#000080ff",
@@ -1179,6 +1218,7 @@ palette!(Cpc {
 	"bright_white": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(GameboyColorType1 {
 	"color_000000ff": "#000000ff",
 	"color_ffad63ff": "#ffad63ff",
@@ -1219,6 +1259,7 @@ palette!(GameboyColorType1 {
 	"color_ffde00ff": "#ffde00ff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Gameboy {
 	"color_9bbc0fff": "#9bbc0fff",
 	"color_8bac0fff": "#8bac0fff",
@@ -1226,6 +1267,7 @@ palette!(Gameboy {
 	"color_0f380fff": "#0f380fff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(MasterSystem {
 	"color_000000ff": "#000000ff",
 	"color_550000ff": "#550000ff",
@@ -1293,6 +1335,7 @@ palette!(MasterSystem {
 	"color_ffffffff": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Msx1 {
 	"transparent": "#00000000",
 	"black": "#010101ff",
@@ -1312,6 +1355,7 @@ palette!(Msx1 {
 	"white": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Msx2 {
 	"transparent": "#00000000",
 	"black": "#010101ff",
@@ -1331,6 +1375,7 @@ palette!(Msx2 {
 	"white": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(NesNtsc {
 	"color_feffffff": "#feffffff",
 	"color_a0a0a0ff": "#a0a0a0ff",
@@ -1388,6 +1433,7 @@ palette!(NesNtsc {
 	"color_000000ff": "#000000ff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Nes {
 	"color_000000ff": "#000000ff",
 	"color_797979ff": "#797979ff",
@@ -1445,6 +1491,7 @@ palette!(Nes {
 	"color_ffffffff": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Teletext {
 	"color_000000ff": "#000000ff",
 	"color_0000ffff": "#0000ffff",
@@ -1456,6 +1503,7 @@ palette!(Teletext {
 	"color_ffffffff": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Vga13h {
 	"color_000000ff": "#000000ff",
 	"color_0000aaff": "#0000aaff",
@@ -1703,6 +1751,7 @@ palette!(Vga13h {
 	"color_2d3141ff": "#2d3141ff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(Virtualboy {
 	"color_000000ff": "#000000ff",
 	"color_550000ff": "#550000ff",
@@ -1710,6 +1759,7 @@ palette!(Virtualboy {
 	"color_ff0000ff": "#ff0000ff",
 });
 
+#[cfg(feature = "aseprite-retro")]
 palette!(ZxSpectrum {
 	"black": "#000000ff",
 	"basic_blue": "#0000c0ff",
@@ -1728,6 +1778,7 @@ palette!(ZxSpectrum {
 	"bright_white": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Mail24 {
 	"color_17111aff": "#17111aff",
 	"color_372538ff": "#372538ff",
@@ -1755,6 +1806,7 @@ palette!(Mail24 {
 	"color_ffffffff": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Nyx8 {
 	"color_08141eff": "#08141eff",
 	"color_0f2a3fff": "#0f2a3fff",
@@ -1766,6 +1818,7 @@ palette!(Nyx8 {
 	"color_4e495fff": "#4e495fff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Pico8 {
 	"black": "#000000ff",
 	"dark_blue": "#1d2b53ff",
@@ -1785,6 +1838,7 @@ palette!(Pico8 {
 	"peach": "#ffccaaff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Bubblegum16 {
 	"color_16171aff": "#16171aff",
 	"color_7f0622ff": "#7f0622ff",
@@ -1804,6 +1858,7 @@ palette!(Bubblegum16 {
 	"color_002859ff": "#002859ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Rosy42 {
 	"color_21181bff": "#21181bff",
 	"color_3b2027ff": "#3b2027ff",
@@ -1849,6 +1904,7 @@ palette!(Rosy42 {
 	"color_ffae70ff": "#ffae70ff",
 });
 
+#[cfg(feature = "aseprite-software")]
 palette!(GoogleUi {
 	"red_200": "#f69988ff",
 	"red_300": "#f36c60ff",
@@ -2006,6 +2062,7 @@ palette!(GoogleUi {
 	"white": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-software")]
 palette!(Minecraft {
 	"white_wool": "#eaededff",
 	"orange_wool": "#f17716ff",
@@ -2057,6 +2114,7 @@ palette!(Minecraft {
 	"black_terracotta": "#251710ff",
 });
 
+#[cfg(feature = "aseprite-software")]
 palette!(Monokai {
 	"blank": "#000000ff",
 	"black": "#1b1d1eff",
@@ -2072,6 +2130,7 @@ palette!(Monokai {
 	"purple": "#ae81ffff",
 });
 
+#[cfg(feature = "aseprite-software")]
 palette!(SmileBasic {
 	"aqua": "#00f8f8ff",
 	"black": "#000000ff",
@@ -2093,6 +2152,7 @@ palette!(SmileBasic {
 	"yellow": "#f8f800ff",
 });
 
+#[cfg(feature = "aseprite-software")]
 palette!(Solarized {
 	"solarized_base03": "#002b36ff",
 	"solarized_base02": "#073642ff",
@@ -2112,6 +2172,7 @@ palette!(Solarized {
 	"solarized_green": "#859900ff",
 });
 
+#[cfg(feature = "aseprite-software")]
 palette!(WebSafeColors {
 	"color_ffffffff": "#ffffffff",
 	"color_ffffccff": "#ffffccff",
@@ -2331,6 +2392,7 @@ palette!(WebSafeColors {
 	"color_000000ff": "#000000ff",
 });
 
+#[cfg(feature = "aseprite-software")]
 palette!(Win16 {
 	"color_000000ff": "#000000ff",
 	"color_820000ff": "#820000ff",
@@ -2350,6 +2412,7 @@ palette!(Win16 {
 	"color_ffffffff": "#ffffffff",
 });
 
+#[cfg(feature = "aseprite-software")]
 palette!(X11 {
 	"blank": "#000000ff",
 	"snow": "#fffafaff",
@@ -2491,6 +2554,7 @@ palette!(X11 {
 	"lightgreen": "#90ee90ff",
 });
 
+#[cfg(feature = "aseprite-pixel-art")]
 palette!(Zughy32 {
 	"color_472d3cff": "#472d3cff",
 	"color_5e3643ff": "#5e3643ff",
@@ -1,5 +1,6 @@
 use macros::palette;
 
+#[cfg(feature = "css")]
 palette!(Basic {
 	"aqua": "#00ffffff",
 	"black": "#000000ff",
@@ -19,6 +20,7 @@ palette!(Basic {
 	"yellow": "#ffff00ff",
 });
 
+#[cfg(feature = "css")]
 palette!(Css {
 	"alice_blue": (0.941, 0.973, 1.0),
 	"antique_white": (0.98, 0.922, 0.843),
@@ -43,7 +45,7 @@ palette!(Css {
 	"dark_goldenrod": (0.722, 0.525, 0.043),
 	"dark_gray": (0.663, 0.663, 0.663),
 	"dark_green": (0.0, 0.392, 0.0),
-	"dark_grey": (0.663, 0.663, 0.663),
+	"dark_grey": alias("dark_gray"),
 	"dark_khaki": (0.741, 0.718, 0.42),
 	"dark_magenta": (0.545, 0.0, 0.545),
 	"dark_olivegreen": (0.333, 0.42, 0.184),
@@ -54,13 +56,13 @@ palette!(Css {
 	"dark_sea_green": (0.561, 0.737, 0.561),
 	"dark_slate_blue": (0.282, 0.239, 0.545),
 	"dark_slate_gray": (0.184, 0.31, 0.31),
-	"dark_slate_grey": (0.184, 0.31, 0.31),
+	"dark_slate_grey": alias("dark_slate_gray"),
 	"dark_turquoise": (0.0, 0.808, 0.82),
 	"dark_violet": (0.58, 0.0, 0.827),
 	"deep_pink": (1.0, 0.078, 0.576),
 	"deep_sky_blue": (0.0, 0.749, 1.0),
 	"dim_gray": (0.412, 0.412, 0.412),
-	"dim_grey": (0.412, 0.412, 0.412),
+	"dim_grey": alias("dim_gray"),
 	"dodger_blue": (0.118, 0.565, 1.0),
 	"fire_brick": (0.698, 0.133, 0.133),
 	"floral_white": (1.0, 0.98, 0.941),
@@ -87,13 +89,13 @@ palette!(Css {
 	"light_goldenrod_yellow": (0.98, 0.98, 0.824),
 	"light_gray": (0.827, 0.827, 0.827),
 	"light_green": (0.565, 0.933, 0.565),
-	"light_grey": (0.827, 0.827, 0.827),
+	"light_grey": alias("light_gray"),
 	"light_pink": (1.0, 0.714, 0.757),
 	"light_salmon": (1.0, 0.627, 0.478),
 	"light_sea_green": (0.125, 0.698, 0.667),
 	"light_sky_blue": (0.529, 0.808, 0.98),
 	"light_slate_gray": (0.467, 0.533, 0.6),
-	"light_slate_grey": (0.467, 0.533, 0.6),
+	"light_slate_grey": alias("light_slate_gray"),
 	"light_steel_blue": (0.69, 0.769, 0.871),
 	"light_yellow": (1.0, 1.0, 0.878),
 	"limegreen": (0.196, 0.804, 0.196),
@@ -140,7 +142,7 @@ palette!(Css {
 	"sky_blue": (0.529, 0.808, 0.922),
 	"slate_blue": (0.416, 0.353, 0.804),
 	"slate_gray": (0.439, 0.502, 0.565),
-	"slate_grey": (0.439, 0.502, 0.565),
+	"slate_grey": alias("slate_gray"),
 	"snow": (1.0, 0.98, 0.98),
 	"spring_green": (0.0, 1.0, 0.498),
 	"steel_blue": (0.275, 0.51, 0.706),
@@ -154,6 +156,7 @@ palette!(Css {
 	"yellow_green": (0.604, 0.804, 0.196),
 });
 
+#[cfg(feature = "tailwind")]
 palette!(Tailwind {
 	"amber_50": (1.0, 0.9843137, 0.92156863),
 	"amber_100": (0.99607843, 0.9529412, 0.78039217),
@@ -1,5 +1,7 @@
 #[cfg(feature = "parse")]
 use crate::error::Error;
+#[cfg(feature = "parse")]
+use alloc::format;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Color {
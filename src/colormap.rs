@@ -0,0 +1,275 @@
+//! Scientific colormaps for heatmaps and plots.
+//!
+//! The sequential maps (viridis, magma, inferno, plasma and cividis from matplotlib, and turbo)
+//! run from low to high values, and the diverging map ([`Colormap::RD_BU`], from `ColorBrewer`)
+//! runs from one extreme through a neutral middle to the other. Each map is a table of evenly
+//! spaced reference colors interpolated in Oklab, within a few 8-bit steps of the originals.
+//!
+//! ```
+//! use bevy_color_palettes::colormap::Colormap;
+//!
+//! let heat = Colormap::INFERNO.sample(0.75);
+//! let legend = Colormap::VIRIDIS.reversed().to_palette(5);
+//! assert_eq!(legend.name(), "viridis_r");
+//! assert_eq!(legend.len(), 5);
+//! ```
+
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::color::Color;
+use crate::gradient::{Gradient, Space};
+use crate::runtime::RuntimePalette;
+
+/// How a colormap's values are laid out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Kind {
+	/// Low to high values, with lightness increasing monotonically for most maps.
+	Sequential,
+	/// Two extremes around a neutral middle at 0.5, for values above and below a reference.
+	Diverging,
+}
+
+/// A continuous colormap, sampled from 0 to 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Colormap {
+	name: &'static str,
+	kind: Kind,
+	stops: &'static [Color],
+	reversed: bool,
+}
+
+impl Colormap {
+	/// Perceptually uniform dark blue to yellow, matplotlib's default.
+	pub const VIRIDIS: Self = Self::new("viridis", Kind::Sequential, &VIRIDIS_STOPS);
+	/// Perceptually uniform black to light yellow through purple.
+	pub const MAGMA: Self = Self::new("magma", Kind::Sequential, &MAGMA_STOPS);
+	/// Perceptually uniform black to pale yellow through red and orange.
+	pub const INFERNO: Self = Self::new("inferno", Kind::Sequential, &INFERNO_STOPS);
+	/// Perceptually uniform blue to yellow through magenta.
+	pub const PLASMA: Self = Self::new("plasma", Kind::Sequential, &PLASMA_STOPS);
+	/// Blue to yellow, readable with red-green color vision deficiencies.
+	pub const CIVIDIS: Self = Self::new("cividis", Kind::Sequential, &CIVIDIS_STOPS);
+	/// A rainbow from dark blue to dark red with smooth lightness, from its author's polynomial
+	/// approximation. Not perceptually uniform; prefer it only where hue distinctions matter.
+	pub const TURBO: Self = Self::new("turbo", Kind::Sequential, &TURBO_STOPS);
+	/// Diverging dark red to dark blue through white.
+	pub const RD_BU: Self = Self::new("rd_bu", Kind::Diverging, &RD_BU_STOPS);
+
+	pub const ALL: [Self; 7] = [
+		Self::VIRIDIS,
+		Self::MAGMA,
+		Self::INFERNO,
+		Self::PLASMA,
+		Self::CIVIDIS,
+		Self::TURBO,
+		Self::RD_BU,
+	];
+
+	/// Creates a colormap from evenly spaced reference colors, from 0 to 1.
+	#[must_use]
+	pub const fn new(name: &'static str, kind: Kind, stops: &'static [Color]) -> Self {
+		Self {
+			name,
+			kind,
+			stops,
+			reversed: false,
+		}
+	}
+
+	/// Returns the map running from 1 to 0, or back to normal if it was already reversed.
+	#[must_use]
+	pub const fn reversed(self) -> Self {
+		Self {
+			reversed: !self.reversed,
+			..self
+		}
+	}
+
+	/// The name of the map, without the `_r` suffix of reversed maps.
+	#[must_use]
+	pub const fn name(&self) -> &'static str {
+		self.name
+	}
+
+	#[must_use]
+	pub const fn kind(&self) -> Kind {
+		self.kind
+	}
+
+	#[must_use]
+	pub const fn is_reversed(&self) -> bool {
+		self.reversed
+	}
+
+	/// The reference colors, from 0 to 1 before reversing.
+	#[must_use]
+	pub const fn stops(&self) -> &'static [Color] {
+		self.stops
+	}
+
+	/// Returns the color at `t`, clamped to 0 to 1. A map without stops is transparent.
+	#[must_use]
+	pub fn sample(&self, t: f32) -> Color {
+		let t = t.clamp(0.0, 1.0);
+		let t = if self.reversed { 1.0 - t } else { t };
+		match self.stops {
+			[] => Color::new(0, 0, 0, 0),
+			[color] => *color,
+			stops => {
+				#[allow(clippy::cast_precision_loss)]
+				let position = t * (stops.len() - 1) as f32;
+				#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+				let index = (position as usize).min(stops.len() - 2);
+				#[allow(clippy::cast_precision_loss)]
+				let local = position - index as f32;
+				Space::Oklab.mix(stops[index], stops[index + 1], local)
+			}
+		}
+	}
+
+	/// Samples `n` evenly spaced colors, including both ends.
+	#[must_use]
+	pub fn steps(&self, n: usize) -> Vec<Color> {
+		match n {
+			0 => Vec::new(),
+			1 => alloc::vec![self.sample(0.5)],
+			_ => (0..n)
+				.map(|i| {
+					#[allow(clippy::cast_precision_loss)]
+					let t = i as f32 / (n - 1) as f32;
+					self.sample(t)
+				})
+				.collect(),
+		}
+	}
+
+	/// Samples `n` colors into a palette named after the map, with a `_r` suffix when reversed,
+	/// and entries named `<map>_0` to `<map>_<n - 1>`.
+	#[must_use]
+	pub fn to_palette(&self, n: usize) -> RuntimePalette {
+		let name = if self.reversed {
+			format!("{}_r", self.name)
+		} else {
+			self.name.into()
+		};
+		let mut palette = RuntimePalette::new(&*name);
+		for (i, color) in self.steps(n).into_iter().enumerate() {
+			palette.push(format!("{name}_{i}"), color);
+		}
+		palette
+	}
+
+	/// Converts to an Oklab [`Gradient`] with a stop per reference color.
+	#[must_use]
+	pub fn to_gradient(&self) -> Gradient {
+		let mut stops = self.stops.to_vec();
+		if self.reversed {
+			stops.reverse();
+		}
+		Gradient::from_colors(&stops, Space::Oklab)
+	}
+}
+
+const fn rgb(r8: u8, g8: u8, b8: u8) -> Color {
+	Color::new(r8, g8, b8, 0xff)
+}
+
+const VIRIDIS_STOPS: [Color; 10] = [
+	rgb(0x44, 0x01, 0x54),
+	rgb(0x48, 0x28, 0x78),
+	rgb(0x3e, 0x49, 0x89),
+	rgb(0x31, 0x68, 0x8e),
+	rgb(0x26, 0x82, 0x8e),
+	rgb(0x1f, 0x9e, 0x89),
+	rgb(0x35, 0xb7, 0x79),
+	rgb(0x6e, 0xce, 0x58),
+	rgb(0xb5, 0xde, 0x2b),
+	rgb(0xfd, 0xe7, 0x25),
+];
+
+const MAGMA_STOPS: [Color; 10] = [
+	rgb(0x00, 0x00, 0x04),
+	rgb(0x18, 0x0f, 0x3d),
+	rgb(0x44, 0x0f, 0x76),
+	rgb(0x72, 0x1f, 0x81),
+	rgb(0x9e, 0x2f, 0x7f),
+	rgb(0xcd, 0x40, 0x71),
+	rgb(0xf1, 0x60, 0x5d),
+	rgb(0xfd, 0x96, 0x68),
+	rgb(0xfe, 0xca, 0x8d),
+	rgb(0xfc, 0xfd, 0xbf),
+];
+
+const INFERNO_STOPS: [Color; 10] = [
+	rgb(0x00, 0x00, 0x04),
+	rgb(0x1b, 0x0c, 0x41),
+	rgb(0x4a, 0x0c, 0x6b),
+	rgb(0x78, 0x1c, 0x6d),
+	rgb(0xa5, 0x2c, 0x60),
+	rgb(0xcf, 0x44, 0x46),
+	rgb(0xed, 0x69, 0x25),
+	rgb(0xfb, 0x9b, 0x06),
+	rgb(0xf7, 0xd1, 0x3d),
+	rgb(0xfc, 0xff, 0xa4),
+];
+
+const PLASMA_STOPS: [Color; 10] = [
+	rgb(0x0d, 0x08, 0x87),
+	rgb(0x46, 0x03, 0x9f),
+	rgb(0x72, 0x01, 0xa8),
+	rgb(0x9c, 0x17, 0x9e),
+	rgb(0xbd, 0x37, 0x86),
+	rgb(0xd8, 0x57, 0x6b),
+	rgb(0xed, 0x79, 0x53),
+	rgb(0xfb, 0x9f, 0x3a),
+	rgb(0xfd, 0xca, 0x26),
+	rgb(0xf0, 0xf9, 0x21),
+];
+
+const CIVIDIS_STOPS: [Color; 10] = [
+	rgb(0x00, 0x22, 0x4e),
+	rgb(0x12, 0x35, 0x70),
+	rgb(0x3b, 0x49, 0x6c),
+	rgb(0x57, 0x5d, 0x6d),
+	rgb(0x70, 0x71, 0x73),
+	rgb(0x8a, 0x87, 0x79),
+	rgb(0xa6, 0x9d, 0x75),
+	rgb(0xc4, 0xb5, 0x6c),
+	rgb(0xe4, 0xcf, 0x5b),
+	rgb(0xfe, 0xe8, 0x38),
+];
+
+const RD_BU_STOPS: [Color; 11] = [
+	rgb(0x67, 0x00, 0x1f),
+	rgb(0xb2, 0x18, 0x2b),
+	rgb(0xd6, 0x60, 0x4d),
+	rgb(0xf4, 0xa5, 0x82),
+	rgb(0xfd, 0xdb, 0xc7),
+	rgb(0xf7, 0xf7, 0xf7),
+	rgb(0xd1, 0xe5, 0xf0),
+	rgb(0x92, 0xc5, 0xde),
+	rgb(0x43, 0x93, 0xc3),
+	rgb(0x21, 0x66, 0xac),
+	rgb(0x05, 0x30, 0x61),
+];
+
+const TURBO_STOPS: [Color; 17] = [
+	rgb(0x23, 0x17, 0x1b),
+	rgb(0x49, 0x3e, 0xae),
+	rgb(0x45, 0x69, 0xee),
+	rgb(0x32, 0x95, 0xf7),
+	rgb(0x26, 0xbc, 0xe1),
+	rgb(0x29, 0xdc, 0xbc),
+	rgb(0x3f, 0xf3, 0x93),
+	rgb(0x65, 0xfd, 0x6e),
+	rgb(0x95, 0xfb, 0x51),
+	rgb(0xc5, 0xec, 0x3c),
+	rgb(0xec, 0xd1, 0x2e),
+	rgb(0xff, 0xad, 0x24),
+	rgb(0xff, 0x82, 0x1d),
+	rgb(0xf0, 0x56, 0x16),
+	rgb(0xcb, 0x2f, 0x0d),
+	rgb(0xa3, 0x13, 0x02),
+	rgb(0x90, 0x0c, 0x00),
+];
@@ -0,0 +1,258 @@
+//! Procedural cosine palettes, `a + b·cos(2π(c·t + d))` per sRGB channel.
+//!
+//! This is the model from Inigo Quilez's *Palettes* article, common in shaders. Parameters are
+//! plain arrays so palettes can be `const`, and [`CosinePalette::fit`] finds parameters that
+//! approximate an existing palette's colors in order:
+//!
+//! ```
+//! use bevy_color_palettes::cosine::CosinePalette;
+//!
+//! const SUNRISE: CosinePalette = CosinePalette::new(
+//!     [0.5, 0.5, 0.5],
+//!     [0.5, 0.5, 0.5],
+//!     [1.0, 1.0, 1.0],
+//!     [0.0, 0.1, 0.2],
+//! );
+//! let colors = SUNRISE.steps(8);
+//! let fitted = CosinePalette::fit(&colors).unwrap();
+//! assert!(fitted.rms_error(&colors) < 0.01);
+//! ```
+
+use core::f32::consts::TAU;
+
+use alloc::vec::Vec;
+
+use crate::color::Color;
+use crate::oklab::unit_to_u8;
+use crate::palette::Palette;
+
+/// The parameters of a cosine palette, each as `[red, green, blue]`.
+///
+/// `a` is the midpoint of each channel, `b` its amplitude, `c` how many cycles it makes from
+/// `t = 0` to `t = 1` and `d` its phase, in cycles. Channels are gamma-encoded sRGB from 0 to 1,
+/// like shader colors, and clamp to that range when sampled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CosinePalette {
+	pub a: [f32; 3],
+	pub b: [f32; 3],
+	pub c: [f32; 3],
+	pub d: [f32; 3],
+}
+
+impl CosinePalette {
+	/// A full hue cycle, red through green and blue back to red.
+	pub const RAINBOW: Self = Self::new(
+		[0.5, 0.5, 0.5],
+		[0.5, 0.5, 0.5],
+		[1.0, 1.0, 1.0],
+		[0.0, 0.33, 0.67],
+	);
+	/// Cream through brown and near black to pale blue.
+	pub const DUSK: Self = Self::new(
+		[0.5, 0.5, 0.5],
+		[0.5, 0.5, 0.5],
+		[1.0, 1.0, 1.0],
+		[0.0, 0.1, 0.2],
+	);
+	/// Peach through rose and near black to amber.
+	pub const EMBER: Self = Self::new(
+		[0.5, 0.5, 0.5],
+		[0.5, 0.5, 0.5],
+		[1.0, 0.7, 0.4],
+		[0.0, 0.15, 0.2],
+	);
+	/// Teal, magenta and purple, cycling twice in red.
+	pub const NEON: Self = Self::new(
+		[0.5, 0.5, 0.5],
+		[0.5, 0.5, 0.5],
+		[2.0, 1.0, 0.0],
+		[0.5, 0.2, 0.25],
+	);
+
+	#[must_use]
+	pub const fn new(a: [f32; 3], b: [f32; 3], c: [f32; 3], d: [f32; 3]) -> Self {
+		Self { a, b, c, d }
+	}
+
+	/// Returns the channels at `t`, unclamped. `t` isn't limited to 0 to 1; most palettes repeat.
+	#[must_use]
+	pub fn channels(&self, t: f32) -> [f32; 3] {
+		core::array::from_fn(|i| self.a[i] + self.b[i] * (TAU * (self.c[i] * t + self.d[i])).cos())
+	}
+
+	/// Returns the opaque color at `t`.
+	#[must_use]
+	pub fn sample(&self, t: f32) -> Color {
+		let [r, g, b] = self.channels(t).map(unit_to_u8);
+		Color::new(r, g, b, 0xff)
+	}
+
+	/// Samples `n` evenly spaced colors from `t = 0` to `t = 1`, including both ends.
+	#[must_use]
+	pub fn steps(&self, n: usize) -> Vec<Color> {
+		(0..n).map(|i| self.sample(position(i, n))).collect()
+	}
+
+	/// Fits parameters to colors spaced evenly from `t = 0` to `t = 1`, such as a ramp or a
+	/// palette in order. Returns `None` without colors.
+	///
+	/// Each channel is fitted on its own. For a given frequency `c` the model is linear in the
+	/// other parameters, so they are solved by least squares, and `c` is searched up to half the
+	/// number of colors, which is as fast as evenly spaced samples can follow, or 4 cycles. Ties
+	/// go to the lowest frequency, so few colors give smooth palettes rather than wiggly ones.
+	#[must_use]
+	#[allow(clippy::many_single_char_names)]
+	pub fn fit(colors: &[Color]) -> Option<Self> {
+		if colors.is_empty() {
+			return None;
+		}
+		let n = colors.len();
+		let mut palette = Self::new([0.0; 3], [0.0; 3], [0.0; 3], [0.0; 3]);
+		for channel in 0..3 {
+			let samples: Vec<(f32, f32)> = colors
+				.iter()
+				.enumerate()
+				.map(|(i, color)| {
+					let value = [color.r8, color.g8, color.b8][channel];
+					(position(i, n), f32::from(value) / 255.0)
+				})
+				.collect();
+			let (a, b, c, d) = fit_channel(&samples);
+			palette.a[channel] = a;
+			palette.b[channel] = b;
+			palette.c[channel] = c;
+			palette.d[channel] = d;
+		}
+		Some(palette)
+	}
+
+	/// Fits parameters to the entries of a palette in declaration order, see
+	/// [`CosinePalette::fit`].
+	#[must_use]
+	pub fn fit_palette<P: Palette>() -> Option<Self> {
+		Self::fit(&P::colors())
+	}
+
+	/// The root mean square channel error, from 0 to 1, of approximating colors spaced evenly
+	/// from `t = 0` to `t = 1` with this palette.
+	#[must_use]
+	pub fn rms_error(&self, colors: &[Color]) -> f32 {
+		if colors.is_empty() {
+			return 0.0;
+		}
+		let n = colors.len();
+		let sum: f32 = colors
+			.iter()
+			.enumerate()
+			.map(|(i, color)| {
+				let channels = self.channels(position(i, n));
+				[color.r8, color.g8, color.b8]
+					.iter()
+					.zip(channels)
+					.map(|(&value, fitted)| {
+						(f32::from(value) / 255.0 - fitted.clamp(0.0, 1.0)).powi(2)
+					})
+					.sum::<f32>()
+			})
+			.sum();
+		#[allow(clippy::cast_precision_loss)]
+		let count = (n * 3) as f32;
+		(sum / count).sqrt()
+	}
+}
+
+/// The position of sample `i` of `n`, evenly spaced from 0 to 1.
+#[allow(clippy::cast_precision_loss)]
+fn position(i: usize, n: usize) -> f32 {
+	if n > 1 {
+		i as f32 / (n - 1) as f32
+	} else {
+		0.0
+	}
+}
+
+/// A channel fit, `(a, b, c, d)`.
+type Fit = (f32, f32, f32, f32);
+
+/// Fits `a + b·cos(2π(c·t + d))` to `(t, value)` samples.
+fn fit_channel(samples: &[(f32, f32)]) -> Fit {
+	#[allow(clippy::cast_precision_loss)]
+	let mean = samples.iter().map(|&(_, v)| v).sum::<f32>() / samples.len() as f32;
+	let constant = (mean, 0.0, 0.0, 0.0);
+	let mut best = (constant, residual(samples, constant));
+
+	#[allow(clippy::cast_precision_loss)]
+	let max_frequency = (samples.len() as f32 / 2.0).min(4.0);
+	// A coarse scan, then a finer one around the best frequency
+	scan(samples, 0.01, max_frequency, 0.01, &mut best);
+	let coarse = best.0.2;
+	if coarse > 0.0 {
+		scan(samples, coarse - 0.01, coarse + 0.01, 0.000_5, &mut best);
+	}
+	best.0
+}
+
+/// Tries frequencies from `from` to `to`, keeping the best fit and its error.
+fn scan(samples: &[(f32, f32)], from: f32, to: f32, step: f32, best: &mut (Fit, f32)) {
+	let mut c = from.max(step);
+	while c <= to {
+		if let Some(fit) = solve(samples, c) {
+			let error = residual(samples, fit);
+			if error < best.1 - 1e-7 {
+				*best = (fit, error);
+			}
+		}
+		c += step;
+	}
+}
+
+/// The sum of squared errors of a channel fit.
+fn residual(samples: &[(f32, f32)], (a, b, c, d): Fit) -> f32 {
+	samples
+		.iter()
+		.map(|&(t, v)| (a + b * (TAU * (c * t + d)).cos() - v).powi(2))
+		.sum()
+}
+
+/// Solves `v ≈ a + p·cos(2πct) + q·sin(2πct)` by least squares, then converts `p` and `q` to
+/// an amplitude and phase. Returns `None` when the frequency can't be told apart from a constant.
+#[allow(clippy::many_single_char_names)]
+fn solve(samples: &[(f32, f32)], c: f32) -> Option<Fit> {
+	// The normal equations, accumulated in f64 to keep them well conditioned
+	let mut m = [[0.0_f64; 3]; 3];
+	let mut r = [0.0_f64; 3];
+	for &(t, v) in samples {
+		let angle = f64::from(TAU * c * t);
+		let row = [1.0, angle.cos(), angle.sin()];
+		for i in 0..3 {
+			for j in 0..3 {
+				m[i][j] += row[i] * row[j];
+			}
+			r[i] += row[i] * f64::from(v);
+		}
+	}
+
+	let det = |m: &[[f64; 3]; 3]| {
+		m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+			- m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+			+ m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+	};
+	let determinant = det(&m);
+	if determinant.abs() < 1e-9 {
+		return None;
+	}
+	// Cramer's rule
+	let [a, p, q] = core::array::from_fn(|column| {
+		let mut replaced = m;
+		for (row, value) in replaced.iter_mut().zip(r) {
+			row[column] = value;
+		}
+		det(&replaced) / determinant
+	});
+
+	#[allow(clippy::cast_possible_truncation)]
+	let (a, p, q) = (a as f32, p as f32, q as f32);
+	let amplitude = p.hypot(q);
+	let phase = ((-q).atan2(p) / TAU).rem_euclid(1.0);
+	Some((a, amplitude, c, phase))
+}
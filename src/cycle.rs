@@ -0,0 +1,150 @@
+//! Consistent colors for plot series and other categories.
+//!
+//! [`ColorCycle`] hands out a palette's colors in order, then lighter and darker variants of
+//! them once the palette runs out, skipping colors too close to the plot background:
+//!
+//! ```
+//! use bevy_color_palettes::{Common, cycle::ColorCycle};
+//!
+//! let series = ColorCycle::new(&[Common::RED, Common::BLUE]).avoiding(Common::BLACK, 0.1);
+//! let colors: Vec<_> = series.iter().take(4).collect();
+//! assert_eq!(&colors[..2], [Common::RED, Common::BLUE]);
+//! assert_eq!(series.get(2), colors[2]);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::color::Color;
+use crate::oklab::{Oklab, Oklch};
+use crate::palette::Palette;
+
+/// Variants closer than this Oklab distance to an earlier color of the cycle are skipped.
+const DUPLICATE: f32 = 0.02;
+
+/// An endless sequence of distinct colors for series, built from a palette.
+///
+/// The cycle is the palette's colors in order, then a round of each color with its Oklch
+/// lightness shifted by each of the variant steps in turn. Once every variant is used, the cycle
+/// starts over, so series `n` always gets [`ColorCycle::get`]`(n)`.
+///
+/// Avoided palette colors are left out along with all their variants, as if they weren't in the
+/// palette, so the colors of later series depend on which colors are avoided.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorCycle {
+	base: Vec<Color>,
+	variants: Vec<f32>,
+	avoid: Vec<(Color, f32)>,
+	cycle: Vec<Color>,
+}
+
+impl ColorCycle {
+	/// Cycles through `colors`, then their variants 0.15 lighter, 0.15 darker, 0.3 lighter and
+	/// 0.3 darker.
+	#[must_use]
+	pub fn new(colors: &[Color]) -> Self {
+		let mut cycle = Self {
+			base: colors.to_vec(),
+			variants: alloc::vec![0.15, -0.15, 0.3, -0.3],
+			avoid: Vec::new(),
+			cycle: Vec::new(),
+		};
+		cycle.rebuild();
+		cycle
+	}
+
+	/// Cycles through the entries of a palette in declaration order.
+	#[must_use]
+	pub fn from_palette<P: Palette>() -> Self {
+		Self::new(&P::colors())
+	}
+
+	/// Sets the lightness shifts of the variant rounds, in Oklch lightness. Empty to only repeat
+	/// the palette.
+	#[must_use]
+	pub fn with_variants(mut self, variants: &[f32]) -> Self {
+		self.variants = variants.to_vec();
+		self.rebuild();
+		self
+	}
+
+	/// Skips colors and variants closer than `min_distance` (Oklab) to `background`, and every
+	/// variant of a skipped palette color. Can be chained to avoid several colors.
+	#[must_use]
+	pub fn avoiding(mut self, background: Color, min_distance: f32) -> Self {
+		self.avoid.push((background, min_distance));
+		self.rebuild();
+		self
+	}
+
+	/// Returns the color of series `n`. Transparent black if every color is avoided.
+	#[must_use]
+	pub fn get(&self, n: usize) -> Color {
+		if self.cycle.is_empty() {
+			Color::new(0, 0, 0, 0)
+		} else {
+			self.cycle[n % self.cycle.len()]
+		}
+	}
+
+	/// Iterates over the colors of series 0, 1, 2 and so on, without end unless every color is
+	/// avoided.
+	pub fn iter(&self) -> impl Iterator<Item = Color> + '_ {
+		self.cycle.iter().copied().cycle()
+	}
+
+	/// Returns the number of distinct colors before the cycle repeats.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.cycle.len()
+	}
+
+	/// Returns whether there are no colors at all, because the palette is empty or every color
+	/// is avoided.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.cycle.is_empty()
+	}
+
+	fn rebuild(&mut self) {
+		let avoided = |color: Color| {
+			let lab = Oklab::from(color);
+			self.avoid
+				.iter()
+				.any(|&(avoid, distance)| lab.distance(Oklab::from(avoid)) < distance)
+		};
+		// Variants of an avoided color would look like a shade of the background
+		let base: Vec<Color> = self
+			.base
+			.iter()
+			.copied()
+			.filter(|&color| !avoided(color))
+			.collect();
+		let mut cycle: Vec<Color> = Vec::new();
+		let rounds = core::iter::once(None).chain(self.variants.iter().copied().map(Some));
+		for shift in rounds {
+			for &color in &base {
+				let color = match shift {
+					None => color,
+					Some(shift) => {
+						let lch = Oklch::from(color);
+						Oklch {
+							l: (lch.l + shift).clamp(0.0, 1.0),
+							..lch
+						}
+						.to_color()
+					}
+				};
+				let lab = Oklab::from(color);
+				// The palette itself is kept as is, but variants mustn't repeat earlier colors
+				let duplicate = shift.is_some()
+					&& cycle
+						.iter()
+						.any(|&earlier| lab.distance(Oklab::from(earlier)) < DUPLICATE);
+				if !duplicate && !avoided(color) {
+					cycle.push(color);
+				}
+			}
+		}
+		self.cycle = cycle;
+	}
+}
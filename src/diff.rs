@@ -0,0 +1,243 @@
+//! Differences between two versions of a palette.
+//!
+//! [`diff`] matches entries by normalized name first, then pairs the leftovers by color, so a
+//! revision is reported as added, removed, renamed and modified entries. The
+//! [`Display`](core::fmt::Display) output is a line per change:
+//!
+//! ```
+//! use bevy_color_palettes::{color::Color, diff::diff, runtime::RuntimePalette};
+//!
+//! let mut old = RuntimePalette::new("Game");
+//! old.push("grass", Color::new(0x59, 0xc1, 0x35, 0xff));
+//! old.push("sky", Color::new(0x4d, 0x9b, 0xe6, 0xff));
+//! let mut new = RuntimePalette::new("Game");
+//! new.push("grass", Color::new(0x4a, 0xb0, 0x2e, 0xff));
+//! new.push("water", Color::new(0x4d, 0x9b, 0xe6, 0xff));
+//!
+//! let changes = diff(&old, &new);
+//! assert_eq!(
+//!     changes.to_string(),
+//!     "~ grass #59c135 -> #4ab02e (ΔE 0.0513)\n> sky -> water #4d9be6\n"
+//! );
+//! assert!(changes.affects(&[Color::new(0x59, 0xc1, 0x35, 0xff)]));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::color::Color;
+use crate::oklab::Oklab;
+use crate::palette::Palette;
+use crate::runtime::RuntimePalette;
+
+/// A change to a single entry.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+	/// An entry only in the new palette.
+	Added { name: String, color: Color },
+	/// An entry only in the old palette.
+	Removed { name: String, color: Color },
+	/// An entry whose color is unchanged but whose name is new.
+	Renamed {
+		from: String,
+		to: String,
+		color: Color,
+	},
+	/// An entry whose name is unchanged but whose color is new, with the Oklab distance between
+	/// both colors.
+	Modified {
+		name: String,
+		from: Color,
+		to: Color,
+		delta_e: f32,
+	},
+}
+
+/// The changes from one palette to another, see [`diff`].
+///
+/// Changes to old entries come first, in the old palette's order, followed by added entries in
+/// the new palette's order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PaletteDiff {
+	pub changes: Vec<Change>,
+}
+
+impl PaletteDiff {
+	/// Returns whether both palettes have the same names and colors, in any order.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.changes.is_empty()
+	}
+
+	/// Iterates over the added entries.
+	pub fn added(&self) -> impl Iterator<Item = &Change> + '_ {
+		self.changes
+			.iter()
+			.filter(|change| matches!(change, Change::Added { .. }))
+	}
+
+	/// Iterates over the removed entries.
+	pub fn removed(&self) -> impl Iterator<Item = &Change> + '_ {
+		self.changes
+			.iter()
+			.filter(|change| matches!(change, Change::Removed { .. }))
+	}
+
+	/// Iterates over the renamed entries.
+	pub fn renamed(&self) -> impl Iterator<Item = &Change> + '_ {
+		self.changes
+			.iter()
+			.filter(|change| matches!(change, Change::Renamed { .. }))
+	}
+
+	/// Iterates over the modified entries.
+	pub fn modified(&self) -> impl Iterator<Item = &Change> + '_ {
+		self.changes
+			.iter()
+			.filter(|change| matches!(change, Change::Modified { .. }))
+	}
+
+	/// Returns the old colors that were removed or modified, the colors that art made with the old
+	/// palette needs updating for. Renamed entries keep their color, so they aren't included.
+	#[must_use]
+	pub fn affected_colors(&self) -> Vec<Color> {
+		self.changes
+			.iter()
+			.filter_map(|change| match change {
+				Change::Removed { color, .. } => Some(*color),
+				Change::Modified { from, .. } => Some(*from),
+				Change::Added { .. } | Change::Renamed { .. } => None,
+			})
+			.collect()
+	}
+
+	/// Returns whether any of `colors`, such as the pixels of a sprite, was removed or modified.
+	#[must_use]
+	pub fn affects(&self, colors: &[Color]) -> bool {
+		let affected = self.affected_colors();
+		colors.iter().any(|color| affected.contains(color))
+	}
+}
+
+/// Compares two palettes.
+///
+/// Entries with the same normalized name are unchanged or [`Change::Modified`]. Of the rest, an
+/// old and a new entry with exactly the same color are [`Change::Renamed`], and anything left is
+/// [`Change::Removed`] or [`Change::Added`].
+#[must_use]
+pub fn diff(old: &RuntimePalette, new: &RuntimePalette) -> PaletteDiff {
+	let mut changes = Vec::new();
+	let mut matched = alloc::vec![false; new.len()];
+
+	let mut unnamed = Vec::new();
+	for (position, entry) in old.iter().enumerate() {
+		let Some(index) = new.position(&entry.name).filter(|&index| !matched[index]) else {
+			unnamed.push((position, entry));
+			continue;
+		};
+		matched[index] = true;
+		let color = new.entries()[index].color;
+		if color != entry.color {
+			changes.push((
+				position,
+				Change::Modified {
+					name: entry.name.clone(),
+					from: entry.color,
+					to: color,
+					delta_e: Oklab::from(entry.color).distance(Oklab::from(color)),
+				},
+			));
+		}
+	}
+
+	for (position, entry) in unnamed {
+		let renamed = new
+			.iter()
+			.zip(&matched)
+			.position(|(by, &matched)| !matched && by.color == entry.color);
+		let change = if let Some(index) = renamed {
+			matched[index] = true;
+			Change::Renamed {
+				from: entry.name.clone(),
+				to: new.entries()[index].name.clone(),
+				color: entry.color,
+			}
+		} else {
+			Change::Removed {
+				name: entry.name.clone(),
+				color: entry.color,
+			}
+		};
+		changes.push((position, change));
+	}
+
+	// Restore the old palette's order, which the two passes above split up
+	changes.sort_by_key(|(position, _)| *position);
+	let mut changes: Vec<Change> = changes.into_iter().map(|(_, change)| change).collect();
+
+	changes.extend(
+		new.iter()
+			.zip(matched)
+			.filter(|(_, matched)| !matched)
+			.map(|(entry, _)| Change::Added {
+				name: entry.name.clone(),
+				color: entry.color,
+			}),
+	);
+	PaletteDiff { changes }
+}
+
+/// Compares two generated palettes, e.g. two versions of a game palette.
+#[must_use]
+pub fn diff_palettes<Old: Palette, New: Palette>() -> PaletteDiff {
+	diff(
+		&RuntimePalette::from_palette::<Old>(),
+		&RuntimePalette::from_palette::<New>(),
+	)
+}
+
+impl fmt::Display for Change {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Added { name, color } => write!(f, "+ {name} {}", Hex(*color)),
+			Self::Removed { name, color } => write!(f, "- {name} {}", Hex(*color)),
+			Self::Renamed { from, to, color } => write!(f, "> {from} -> {to} {}", Hex(*color)),
+			Self::Modified {
+				name,
+				from,
+				to,
+				delta_e,
+			} => write!(
+				f,
+				"~ {name} {} -> {} (ΔE {delta_e:.4})",
+				Hex(*from),
+				Hex(*to)
+			),
+		}
+	}
+}
+
+impl fmt::Display for PaletteDiff {
+	/// Writes a line per change: `+` added, `-` removed, `>` renamed and `~` modified.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for change in &self.changes {
+			writeln!(f, "{change}")?;
+		}
+		Ok(())
+	}
+}
+
+/// Formats a color as `#rrggbb`, or `#rrggbbaa` when it isn't opaque.
+struct Hex(Color);
+
+impl fmt::Display for Hex {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let Color { r8, g8, b8, a8, .. } = self.0;
+		write!(f, "#{r8:02x}{g8:02x}{b8:02x}")?;
+		if a8 != 0xff {
+			write!(f, "{a8:02x}")?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,156 @@
+//! Picking the most distinguishable colors of a palette.
+//!
+//! [`select`] picks `count` colors that maximize the smallest distance between any two of them,
+//! and between them and the background colors to avoid, for player and team colors:
+//!
+//! ```
+//! use bevy_color_palettes::Common;
+//! use bevy_color_palettes::distinct::{DistinctOptions, select};
+//!
+//! let colors = Common::all();
+//! let options = DistinctOptions {
+//!     avoid: &[Common::BLACK],
+//!     ..DistinctOptions::default()
+//! };
+//! let teams = select(&colors, 4, &options);
+//! assert_eq!(teams.indices.len(), 4);
+//! assert!(!teams.indices.iter().any(|&index| colors[index] == Common::BLACK));
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::color::Color;
+use crate::remap::Metric;
+
+/// Options for [`select`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DistinctOptions<'a> {
+	/// How the distance between colors is measured.
+	pub metric: Metric,
+	/// Palette indices that are always selected, first and in order, such as colors already in
+	/// use.
+	pub fixed: &'a [usize],
+	/// Background colors the selection must stay distinguishable from. They count as selected
+	/// colors when measuring distances, but are never returned.
+	pub avoid: &'a [Color],
+	/// Palette colors closer than this to an `avoid` color are never selected, unless fixed.
+	pub avoid_distance: f32,
+}
+
+/// The colors picked by [`select`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Selection {
+	/// Palette indices, fixed indices first and then in the order they were picked.
+	pub indices: Vec<usize>,
+	/// The smallest distance between two selected colors, or between a selected color and an
+	/// avoided one. `None` with fewer than two colors to compare.
+	pub min_distance: Option<f32>,
+}
+
+/// Picks up to `count` colors of a palette that are as distinguishable as possible.
+///
+/// This is greedy farthest-point selection: after the fixed colors, each pick is the color
+/// farthest from everything selected or avoided so far, starting from the two most different
+/// colors when there is nothing to measure from. Swaps that increase the smallest distance are
+/// then made until there are none left. Ties go to the lowest index, so the result only depends
+/// on the inputs.
+///
+/// Fewer than `count` colors are returned when the palette runs out of candidates. Fixed indices
+/// outside the palette are ignored.
+#[must_use]
+pub fn select(colors: &[Color], count: usize, options: &DistinctOptions) -> Selection {
+	let metric = options.metric;
+	let distances: Vec<Vec<f32>> = colors
+		.iter()
+		.map(|&a| colors.iter().map(|&b| metric.distance(a, b)).collect())
+		.collect();
+	let background: Vec<f32> = colors
+		.iter()
+		.map(|&color| {
+			options
+				.avoid
+				.iter()
+				.map(|&avoid| metric.distance(color, avoid))
+				.fold(f32::INFINITY, f32::min)
+		})
+		.collect();
+
+	let mut indices: Vec<usize> = Vec::new();
+	for &index in options.fixed {
+		if index < colors.len() && !indices.contains(&index) && indices.len() < count {
+			indices.push(index);
+		}
+	}
+	let fixed = indices.len();
+	let candidates: Vec<usize> = (0..colors.len())
+		.filter(|index| !indices.contains(index) && background[*index] >= options.avoid_distance)
+		.collect();
+
+	// The distance from a candidate to the closest selected or avoided color
+	let nearest = |candidate: usize, selected: &[usize]| {
+		selected
+			.iter()
+			.map(|&index| distances[candidate][index])
+			.fold(background[candidate], f32::min)
+	};
+
+	if indices.is_empty() && options.avoid.is_empty() && count >= 2 {
+		let farthest = candidates
+			.iter()
+			.enumerate()
+			.flat_map(|(i, &a)| candidates[i + 1..].iter().map(move |&b| (a, b)))
+			.max_by(|x, y| {
+				distances[x.0][x.1]
+					.total_cmp(&distances[y.0][y.1])
+					.then(y.cmp(x))
+			});
+		if let Some((a, b)) = farthest {
+			indices.extend([a, b]);
+		}
+	}
+	while indices.len() < count {
+		let next = candidates
+			.iter()
+			.filter(|index| !indices.contains(index))
+			.map(|&index| (index, nearest(index, &indices)))
+			.max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+		match next {
+			Some((index, _)) => indices.push(index),
+			None => break,
+		}
+	}
+
+	let score = |selected: &[usize]| {
+		selected
+			.iter()
+			.enumerate()
+			.map(|(i, &index)| nearest(index, &selected[..i]))
+			.fold(f32::INFINITY, f32::min)
+	};
+	let mut best = score(&indices);
+	let mut improved = true;
+	while improved {
+		improved = false;
+		for slot in fixed..indices.len() {
+			for &candidate in &candidates {
+				if indices.contains(&candidate) {
+					continue;
+				}
+				let previous = indices[slot];
+				indices[slot] = candidate;
+				let swapped = score(&indices);
+				if swapped > best {
+					best = swapped;
+					improved = true;
+				} else {
+					indices[slot] = previous;
+				}
+			}
+		}
+	}
+
+	Selection {
+		min_distance: best.is_finite().then_some(best),
+		indices,
+	}
+}
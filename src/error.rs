@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 #[cfg(feature = "parse")]
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
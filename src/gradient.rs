@@ -0,0 +1,591 @@
+//! Multi-stop gradients authored in palette colors.
+//!
+//! ```
+//! use bevy_color_palettes::Common;
+//! use bevy_color_palettes::gradient::{Easing, Gradient, Space};
+//!
+//! let health = Gradient::new(Space::Oklab)
+//!     .stop(0.0, Common::RED)
+//!     .stop(0.5, Common::YELLOW)
+//!     .eased_stop(1.0, Common::GREEN, Easing::EaseOut);
+//! assert_eq!(health.sample(0.0), Common::RED);
+//! assert_eq!(health.steps(5).len(), 5);
+//! ```
+//!
+//! With the `parse` feature, gradients also convert from and to CSS `linear-gradient()`.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write as _;
+
+use crate::color::Color;
+use crate::oklab::{Oklab, Oklch, linear_to_srgb, srgb_to_linear, unit_to_u8};
+use crate::palette::Palette;
+
+/// The color space a gradient interpolates in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Space {
+	/// Gamma-encoded sRGB, how CSS and most image editors blend by default.
+	Srgb,
+	/// Linear-light sRGB, physically correct mixing of light.
+	LinearSrgb,
+	/// Oklab, perceptually even steps without hue sweeps.
+	#[default]
+	Oklab,
+	/// Oklch, keeping chroma up by travelling the shorter way around the hue wheel.
+	Oklch,
+}
+
+impl Space {
+	/// The name of the space in CSS `in <space>` syntax.
+	#[must_use]
+	pub const fn css_name(self) -> &'static str {
+		match self {
+			Space::Srgb => "srgb",
+			Space::LinearSrgb => "srgb-linear",
+			Space::Oklab => "oklab",
+			Space::Oklch => "oklch",
+		}
+	}
+
+	/// Interpolates from `a` to `b` in this space.
+	#[must_use]
+	pub fn mix(self, a: Color, b: Color, t: f32) -> Color {
+		match self {
+			Space::Srgb => {
+				let lerp = |a: u8, b: u8| {
+					let (a, b) = (f32::from(a) / 255.0, f32::from(b) / 255.0);
+					unit_to_u8(a + (b - a) * t)
+				};
+				Color::new(
+					lerp(a.r8, b.r8),
+					lerp(a.g8, b.g8),
+					lerp(a.b8, b.b8),
+					lerp(a.a8, b.a8),
+				)
+			}
+			Space::LinearSrgb => {
+				let lerp = |a: u8, b: u8| {
+					let (a, b) = (
+						srgb_to_linear(f32::from(a) / 255.0),
+						srgb_to_linear(f32::from(b) / 255.0),
+					);
+					unit_to_u8(linear_to_srgb(a + (b - a) * t))
+				};
+				let alpha = f32::from(a.a8) + (f32::from(b.a8) - f32::from(a.a8)) * t;
+				Color::new(
+					lerp(a.r8, b.r8),
+					lerp(a.g8, b.g8),
+					lerp(a.b8, b.b8),
+					unit_to_u8(alpha / 255.0),
+				)
+			}
+			Space::Oklab => Oklab::from(a).mix(Oklab::from(b), t).to_color(),
+			Space::Oklch => Oklch::from(a).mix(Oklch::from(b), t).to_color(),
+		}
+	}
+}
+
+/// How a gradient segment progresses between its two stops.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Easing {
+	#[default]
+	Linear,
+	/// Starts slowly, quadratic.
+	EaseIn,
+	/// Ends slowly, quadratic.
+	EaseOut,
+	/// Starts and ends slowly, smoothstep.
+	EaseInOut,
+	/// Holds the first color until the next stop, for banded bars and toon skies.
+	Step,
+}
+
+impl Easing {
+	/// Maps linear progress through a segment, from 0 to 1, to eased progress.
+	#[must_use]
+	pub fn apply(self, t: f32) -> f32 {
+		let t = t.clamp(0.0, 1.0);
+		match self {
+			Easing::Linear => t,
+			Easing::EaseIn => t * t,
+			Easing::EaseOut => t * (2.0 - t),
+			Easing::EaseInOut => t * t * (3.0 - 2.0 * t),
+			Easing::Step => {
+				if t < 1.0 {
+					0.0
+				} else {
+					1.0
+				}
+			}
+		}
+	}
+}
+
+/// A color at a position along a gradient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stop {
+	/// The position of the stop, from 0 to 1.
+	pub position: f32,
+	pub color: Color,
+	/// The easing of the segment leading into this stop from the previous one.
+	pub easing: Easing,
+}
+
+/// A gradient of color stops, sampled from 0 to 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+	/// The stops, sorted by position.
+	pub stops: Vec<Stop>,
+	pub space: Space,
+	/// The CSS direction in degrees, clockwise from "to top". Only used by CSS conversions.
+	pub angle: f32,
+}
+
+impl Default for Gradient {
+	fn default() -> Self {
+		Self::new(Space::default())
+	}
+}
+
+impl Gradient {
+	/// Creates a gradient without stops, pointing down like CSS.
+	#[must_use]
+	pub const fn new(space: Space) -> Self {
+		Self {
+			stops: Vec::new(),
+			space,
+			angle: 180.0,
+		}
+	}
+
+	/// Spaces colors evenly from 0 to 1, e.g. a ramp from [`crate::ramp::generate`].
+	#[must_use]
+	pub fn from_colors(colors: &[Color], space: Space) -> Self {
+		let mut gradient = Self::new(space);
+		#[allow(clippy::cast_precision_loss)]
+		let last = colors.len().saturating_sub(1).max(1) as f32;
+		for (i, &color) in colors.iter().enumerate() {
+			#[allow(clippy::cast_precision_loss)]
+			let position = i as f32 / last;
+			gradient = gradient.stop(position, color);
+		}
+		gradient
+	}
+
+	/// Spaces the colors of a palette evenly from 0 to 1, in declaration order.
+	#[must_use]
+	pub fn from_palette<P: Palette>(space: Space) -> Self {
+		Self::from_colors(&P::colors(), space)
+	}
+
+	/// Adds a linear stop, keeping the stops sorted. Stops at the same position make a hard edge.
+	#[must_use]
+	pub fn stop(self, position: f32, color: Color) -> Self {
+		self.eased_stop(position, color, Easing::Linear)
+	}
+
+	/// Adds a stop reached from the previous one with `easing`, keeping the stops sorted.
+	#[must_use]
+	pub fn eased_stop(mut self, position: f32, color: Color, easing: Easing) -> Self {
+		let position = position.clamp(0.0, 1.0);
+		let index = self.stops.partition_point(|stop| stop.position <= position);
+		self.stops.insert(
+			index,
+			Stop {
+				position,
+				color,
+				easing,
+			},
+		);
+		self
+	}
+
+	/// Sets the CSS direction in degrees.
+	#[must_use]
+	pub const fn with_angle(mut self, angle: f32) -> Self {
+		self.angle = angle;
+		self
+	}
+
+	/// Returns the color at `t`, clamped to 0 to 1.
+	///
+	/// Positions before the first stop and after the last take their colors, and NaN takes the
+	/// first stop's color. A gradient without stops is transparent.
+	#[must_use]
+	pub fn sample(&self, t: f32) -> Color {
+		let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+			return Color::new(0, 0, 0, 0);
+		};
+		let t = t.clamp(0.0, 1.0);
+		if t.is_nan() || t <= first.position {
+			return first.color;
+		}
+		if t >= last.position {
+			return last.color;
+		}
+
+		let next = self.stops.partition_point(|stop| stop.position <= t);
+		let (from, to) = (&self.stops[next - 1], &self.stops[next]);
+		let length = to.position - from.position;
+		let local = if length > 0.0 {
+			(t - from.position) / length
+		} else {
+			1.0
+		};
+		self.space.mix(from.color, to.color, to.easing.apply(local))
+	}
+
+	/// Samples `n` evenly spaced colors, including both ends.
+	#[must_use]
+	pub fn steps(&self, n: usize) -> Vec<Color> {
+		match n {
+			0 => Vec::new(),
+			1 => alloc::vec![self.sample(0.0)],
+			_ => (0..n)
+				.map(|i| {
+					#[allow(clippy::cast_precision_loss)]
+					let t = i as f32 / (n - 1) as f32;
+					self.sample(t)
+				})
+				.collect(),
+		}
+	}
+
+	/// Serializes to a CSS `linear-gradient()`, with hex colors and percentage positions.
+	///
+	/// CSS has no per-segment easing, so eased segments are written as linear ones.
+	#[must_use]
+	pub fn to_css(&self) -> String {
+		let mut css = String::from("linear-gradient(");
+		let _ = write!(css, "{}deg", self.angle);
+		if self.space != Space::Srgb {
+			let _ = write!(css, " in {}", self.space.css_name());
+		}
+		for stop in &self.stops {
+			let Color { r8, g8, b8, a8, .. } = stop.color;
+			let _ = write!(css, ", #{r8:02x}{g8:02x}{b8:02x}");
+			if a8 != 255 {
+				let _ = write!(css, "{a8:02x}");
+			}
+			let _ = write!(css, " {}%", stop.position * 100.0);
+		}
+		css.push(')');
+		css
+	}
+}
+
+#[cfg(feature = "parse")]
+mod css {
+	use alloc::string::ToString;
+	use alloc::vec::Vec;
+
+	use super::{Gradient, Space, Stop};
+	use crate::color::Color;
+	use crate::error::Error;
+
+	impl Gradient {
+		/// Parses a CSS `linear-gradient()`.
+		///
+		/// Supports an angle (`deg`, `grad`, `rad`, `turn`) or `to <side>` direction, an
+		/// `in srgb|srgb-linear|oklab|oklch` interpolation space, and stops with one or two
+		/// percentages. Colors may be hex, `rgb()`/`rgba()`, `transparent`, or with the `css`
+		/// feature a CSS color name. Missing positions are filled in as CSS does, and stops
+		/// outside 0% to 100% are moved onto the ends with the colors the gradient has there.
+		///
+		/// # Errors
+		/// - `Error::ParseError` - If the gradient or one of its colors is malformed.
+		pub fn parse_css(css: &str) -> Result<Self, Error> {
+			let body = css
+				.trim()
+				.strip_prefix("linear-gradient(")
+				.and_then(|rest| rest.strip_suffix(')'))
+				.ok_or_else(|| error("expected `linear-gradient(...)`"))?;
+
+			let mut args = split_top_level(body).into_iter().peekable();
+			let mut gradient = Gradient::new(Space::Srgb);
+			if let Some(first) = args.peek()
+				&& let Some((angle, space)) = parse_prelude(first)?
+			{
+				gradient.angle = angle.unwrap_or(180.0);
+				gradient.space = space.unwrap_or(Space::Srgb);
+				args.next();
+			}
+
+			let mut stops: Vec<(Color, Option<f32>)> = Vec::new();
+			for arg in args {
+				let (color, positions) = split_color(arg);
+				let color = parse_color(color)?;
+				let positions = positions
+					.split_whitespace()
+					.map(parse_percentage)
+					.collect::<Result<Vec<_>, _>>()?;
+				match positions.as_slice() {
+					[] => stops.push((color, None)),
+					[position] => stops.push((color, Some(*position))),
+					[from, to] => {
+						stops.push((color, Some(*from)));
+						stops.push((color, Some(*to)));
+					}
+					_ => return Err(error("a color stop has at most two positions")),
+				}
+			}
+			if stops.len() < 2 {
+				return Err(error("a gradient needs at least two color stops"));
+			}
+
+			gradient.stops = fill_positions(&stops)
+				.into_iter()
+				.zip(stops)
+				.map(|(position, (color, _))| Stop {
+					position,
+					color,
+					easing: super::Easing::Linear,
+				})
+				.collect();
+			clip_stops(&mut gradient);
+			Ok(gradient)
+		}
+	}
+
+	/// Moves stops outside 0% to 100% onto the ends, with the colors the gradient has there, so
+	/// the visible part of the gradient is unchanged.
+	fn clip_stops(gradient: &mut Gradient) {
+		let (Some(first), Some(last)) = (gradient.stops.first(), gradient.stops.last()) else {
+			return;
+		};
+		let start = (first.position < 0.0).then(|| gradient.sample(0.0));
+		let end = (last.position > 1.0).then(|| gradient.sample(1.0));
+		if start.is_none() && end.is_none() {
+			return;
+		}
+
+		let mut stops: Vec<Stop> = gradient
+			.stops
+			.iter()
+			.filter(|stop| (0.0..=1.0).contains(&stop.position))
+			.copied()
+			.collect();
+		let stop = |position, color| Stop {
+			position,
+			color,
+			easing: super::Easing::Linear,
+		};
+		if let Some(color) = start
+			&& stops.first().is_none_or(|first| first.position > 0.0)
+		{
+			stops.insert(0, stop(0.0, color));
+		}
+		if let Some(color) = end
+			&& stops.last().is_none_or(|last| last.position < 1.0)
+		{
+			stops.push(stop(1.0, color));
+		}
+		// Every stop was on one side, so the visible part is a single color
+		if let [only] = stops[..] {
+			let position = 1.0 - only.position;
+			stops.push(stop(position, only.color));
+			stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+		}
+		gradient.stops = stops;
+	}
+
+	fn error(message: &str) -> Error {
+		Error::ParseError(message.to_string())
+	}
+
+	/// Splits on commas outside of parentheses.
+	fn split_top_level(body: &str) -> Vec<&str> {
+		let mut parts = Vec::new();
+		let (mut depth, mut start) = (0_usize, 0);
+		for (i, c) in body.char_indices() {
+			match c {
+				'(' => depth += 1,
+				')' => depth = depth.saturating_sub(1),
+				',' if depth == 0 => {
+					parts.push(body[start..i].trim());
+					start = i + 1;
+				}
+				_ => {}
+			}
+		}
+		parts.push(body[start..].trim());
+		parts
+	}
+
+	/// Parses the optional direction and interpolation space before the first stop.
+	#[allow(clippy::type_complexity)]
+	fn parse_prelude(arg: &str) -> Result<Option<(Option<f32>, Option<Space>)>, Error> {
+		let (direction, space) = match arg.split_once("in ") {
+			Some((direction, space)) if direction.is_empty() || direction.ends_with(' ') => {
+				(direction.trim(), Some(space.trim()))
+			}
+			_ => (arg.trim(), None),
+		};
+
+		let angle = if direction.is_empty() {
+			None
+		} else if let Some(side) = direction.strip_prefix("to ") {
+			Some(parse_side(side.trim())?)
+		} else if let Some(angle) = parse_angle(direction) {
+			Some(angle)
+		} else if space.is_none() {
+			return Ok(None);
+		} else {
+			return Err(error("expected an angle or `to <side>`"));
+		};
+
+		let space = match space {
+			None => None,
+			Some(space) => Some(match space.split_whitespace().next() {
+				Some("srgb") => Space::Srgb,
+				Some("srgb-linear") => Space::LinearSrgb,
+				Some("oklab") => Space::Oklab,
+				Some("oklch") => Space::Oklch,
+				_ => return Err(error("unsupported interpolation space")),
+			}),
+		};
+		Ok(Some((angle, space)))
+	}
+
+	fn parse_side(side: &str) -> Result<f32, Error> {
+		let mut words: Vec<&str> = side.split_whitespace().collect();
+		words.sort_unstable();
+		Ok(match words.as_slice() {
+			["top"] => 0.0,
+			["right", "top"] => 45.0,
+			["right"] => 90.0,
+			["bottom", "right"] => 135.0,
+			["bottom"] => 180.0,
+			["bottom", "left"] => 225.0,
+			["left"] => 270.0,
+			["left", "top"] => 315.0,
+			_ => return Err(error("expected `to` a side or corner")),
+		})
+	}
+
+	fn parse_angle(angle: &str) -> Option<f32> {
+		let units = [
+			("deg", 1.0),
+			("grad", 0.9),
+			("rad", 180.0 / core::f32::consts::PI),
+			("turn", 360.0),
+		];
+		units.iter().find_map(|(unit, scale)| {
+			let value = angle.strip_suffix(unit)?.parse::<f32>().ok()?;
+			Some(value * scale)
+		})
+	}
+
+	fn parse_percentage(position: &str) -> Result<f32, Error> {
+		position
+			.strip_suffix('%')
+			.and_then(|value| value.parse::<f32>().ok())
+			.map(|value| value / 100.0)
+			.ok_or_else(|| error("color stop positions must be percentages"))
+	}
+
+	/// Splits a stop into its color and the positions after it.
+	fn split_color(stop: &str) -> (&str, &str) {
+		let end = match stop.find('(') {
+			Some(open) => stop[open..]
+				.find(')')
+				.map_or(stop.len(), |close| open + close + 1),
+			None => stop.find(char::is_whitespace).unwrap_or(stop.len()),
+		};
+		(stop[..end].trim(), stop[end..].trim())
+	}
+
+	fn parse_color(color: &str) -> Result<Color, Error> {
+		if color.starts_with('#') {
+			return Color::try_parse(color);
+		}
+		if color.eq_ignore_ascii_case("transparent") {
+			return Ok(Color::new(0, 0, 0, 0));
+		}
+		if let Some(args) = color
+			.strip_prefix("rgba(")
+			.or_else(|| color.strip_prefix("rgb("))
+			.and_then(|rest| rest.strip_suffix(')'))
+		{
+			return parse_rgb(args);
+		}
+		#[cfg(feature = "css")]
+		if let Some(color) = crate::bevy::Basic::get(color).or_else(|| crate::bevy::Css::get(color))
+		{
+			return Ok(color);
+		}
+		Err(Error::ParseError(alloc::format!(
+			"unsupported color `{color}`"
+		)))
+	}
+
+	/// Parses `r, g, b[, a]` or `r g b[ / a]`, with numbers or percentages.
+	fn parse_rgb(args: &str) -> Result<Color, Error> {
+		let parts: Vec<&str> = args
+			.split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+			.filter(|part| !part.is_empty())
+			.collect();
+		let channel = |part: &str, max: f32| -> Result<u8, Error> {
+			let value = match part.strip_suffix('%') {
+				Some(percent) => percent.parse::<f32>().map(|v| v / 100.0),
+				None => part.parse::<f32>().map(|v| v / max),
+			}
+			.map_err(|_| error("malformed `rgb()` channel"))?;
+			Ok(crate::oklab::unit_to_u8(value))
+		};
+		match parts.as_slice() {
+			[r, g, b] => Ok(Color::new(
+				channel(r, 255.0)?,
+				channel(g, 255.0)?,
+				channel(b, 255.0)?,
+				255,
+			)),
+			[r, g, b, a] => Ok(Color::new(
+				channel(r, 255.0)?,
+				channel(g, 255.0)?,
+				channel(b, 255.0)?,
+				channel(a, 1.0)?,
+			)),
+			_ => Err(error("`rgb()` takes three or four channels")),
+		}
+	}
+
+	/// Fills in missing stop positions and clamps them in order, as CSS does.
+	fn fill_positions(stops: &[(Color, Option<f32>)]) -> Vec<f32> {
+		let mut positions: Vec<Option<f32>> = stops.iter().map(|(_, position)| *position).collect();
+		let last = positions.len() - 1;
+		positions[0] = Some(positions[0].unwrap_or(0.0));
+		positions[last] = Some(positions[last].unwrap_or(1.0));
+
+		let mut max = f32::NEG_INFINITY;
+		for position in positions.iter_mut().flatten() {
+			max = max.max(*position);
+			*position = max;
+		}
+
+		let mut filled = Vec::with_capacity(positions.len());
+		let mut i = 0;
+		while i < positions.len() {
+			if let Some(position) = positions[i] {
+				filled.push(position);
+				i += 1;
+				continue;
+			}
+			let start = filled[i - 1];
+			let end_index = (i..positions.len())
+				.find(|&j| positions[j].is_some())
+				.unwrap_or(last);
+			let end = positions[end_index].unwrap_or(1.0);
+			#[allow(clippy::cast_precision_loss)]
+			let gaps = (end_index - i + 1) as f32;
+			for k in i..end_index {
+				#[allow(clippy::cast_precision_loss)]
+				let step = (k - i + 1) as f32;
+				filled.push(start + (end - start) * step / gaps);
+			}
+			i = end_index;
+		}
+		filled
+	}
+}
@@ -0,0 +1,114 @@
+//! Color harmonies from a seed color, optionally snapped to a palette.
+//!
+//! Harmonies rotate the seed's Oklch hue, keeping its lightness and chroma, so the generated
+//! colors look as bright and as saturated as the seed. [`Harmony::generate_in`] then snaps them
+//! to the closest colors of a palette, for picking accents without leaving the project palette:
+//!
+//! ```
+//! use bevy_color_palettes::{Common, harmony::Harmony};
+//! use bevy_color_palettes::remap::{Assignment, Metric};
+//!
+//! let accents = Harmony::Triadic.generate_in(Common::RED, &Common::all(), Metric::Oklab, Assignment::OneToOne);
+//! assert_eq!(accents, [Common::RED, Common::GREEN, Common::BLUE]);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::color::Color;
+use crate::oklab::Oklch;
+use crate::remap::{Assignment, Metric, remap};
+
+/// A color harmony, a set of colors related to a seed by hue or lightness.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Harmony {
+	/// The seed and its opposite hue.
+	Complementary,
+	/// The seed and the two hues 30° either side of its opposite.
+	SplitComplementary,
+	/// The seed and its neighbouring hues 30° either side.
+	Analogous,
+	/// The seed and the hues a third of the way around the wheel.
+	Triadic,
+	/// The seed and three hues making a rectangle on the wheel, at 60°, 180° and 240°.
+	Tetradic,
+	/// The seed and four colors of its hue at other lightnesses.
+	Monochromatic,
+}
+
+impl Harmony {
+	pub const ALL: [Self; 6] = [
+		Self::Complementary,
+		Self::SplitComplementary,
+		Self::Analogous,
+		Self::Triadic,
+		Self::Tetradic,
+		Self::Monochromatic,
+	];
+
+	/// The hue rotations of the colors after the seed, in degrees. Empty for
+	/// [`Harmony::Monochromatic`], which varies lightness instead.
+	#[must_use]
+	pub const fn hue_offsets(self) -> &'static [f32] {
+		match self {
+			Self::Complementary => &[180.0],
+			Self::SplitComplementary => &[150.0, 210.0],
+			Self::Analogous => &[-30.0, 30.0],
+			Self::Triadic => &[120.0, 240.0],
+			Self::Tetradic => &[60.0, 180.0, 240.0],
+			Self::Monochromatic => &[],
+		}
+	}
+
+	/// Generates the harmony, starting with the seed itself.
+	///
+	/// [`Harmony::Monochromatic`] spaces five lightnesses evenly from 0.3 to 0.9 and replaces the
+	/// one closest to the seed with the seed, giving the others from darkest to lightest.
+	#[must_use]
+	pub fn generate(self, seed: Color) -> Vec<Color> {
+		const LIGHTNESS: [f32; 5] = [0.3, 0.45, 0.6, 0.75, 0.9];
+
+		let base = Oklch::from(seed);
+		let mut colors = alloc::vec![seed];
+		if self == Self::Monochromatic {
+			let closest = LIGHTNESS
+				.iter()
+				.enumerate()
+				.min_by(|a, b| (a.1 - base.l).abs().total_cmp(&(b.1 - base.l).abs()))
+				.map_or(0, |(index, _)| index);
+			colors.extend(
+				LIGHTNESS
+					.iter()
+					.enumerate()
+					.filter(|&(index, _)| index != closest)
+					.map(|(_, &l)| Oklch { l, ..base }.to_color()),
+			);
+		} else {
+			colors.extend(self.hue_offsets().iter().map(|offset| {
+				Oklch {
+					h: (base.h + offset).rem_euclid(360.0),
+					..base
+				}
+				.to_color()
+			}));
+		}
+		colors
+	}
+
+	/// Generates the harmony and replaces each color with a color of `palette`, see
+	/// [`remap`].
+	///
+	/// [`Assignment::OneToOne`] keeps the snapped colors distinct while the palette has enough
+	/// colors; [`Assignment::Nearest`] may snap several colors to the same one. Returns an empty
+	/// list for an empty palette.
+	#[must_use]
+	pub fn generate_in(
+		self,
+		seed: Color,
+		palette: &[Color],
+		metric: Metric,
+		assignment: Assignment,
+	) -> Vec<Color> {
+		remap(&self.generate(seed), palette, metric, assignment)
+			.map_or_else(Vec::new, |table| table.apply(palette))
+	}
+}
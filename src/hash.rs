@@ -0,0 +1,181 @@
+//! Stable colors for IDs, names and other keys.
+//!
+//! [`color_for`] hashes a key with [`StableHasher`] and maps it evenly onto a palette, so the
+//! same user name, peer ID or entity always gets the same color, across runs and platforms:
+//!
+//! ```
+//! use bevy_color_palettes::{Common, hash::color_for};
+//!
+//! let alice = color_for("alice", &Common::all()).unwrap();
+//! assert_eq!(color_for("alice", &Common::all()), Some(alice));
+//! ```
+//!
+//! With the `std` feature, `KeyColors` also keeps keys clear of a background color.
+
+use core::hash::{Hash, Hasher};
+
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
+use crate::color::Color;
+#[cfg(feature = "std")]
+use crate::oklab::Oklab;
+#[cfg(feature = "std")]
+use crate::palette::Palette;
+
+/// A hasher whose output never changes between runs, platforms or versions of this crate, unlike
+/// the standard library's randomly seeded hashers.
+///
+/// This is 64-bit FNV-1a followed by a `SplitMix64` finalizer, so similar keys such as `"peer1"`
+/// and `"peer2"` still land far apart. Integers hash as little-endian bytes, and `usize` and
+/// `isize` as 64-bit integers, so 32-bit and 64-bit targets agree. It isn't resistant to
+/// deliberate collisions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StableHasher {
+	state: u64,
+}
+
+impl StableHasher {
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			state: 0xcbf2_9ce4_8422_2325,
+		}
+	}
+}
+
+impl Default for StableHasher {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Hasher for StableHasher {
+	fn write(&mut self, bytes: &[u8]) {
+		for &byte in bytes {
+			self.state ^= u64::from(byte);
+			self.state = self.state.wrapping_mul(0x0100_0000_01b3);
+		}
+	}
+
+	fn write_u16(&mut self, i: u16) {
+		self.write(&i.to_le_bytes());
+	}
+
+	fn write_u32(&mut self, i: u32) {
+		self.write(&i.to_le_bytes());
+	}
+
+	fn write_u64(&mut self, i: u64) {
+		self.write(&i.to_le_bytes());
+	}
+
+	fn write_u128(&mut self, i: u128) {
+		self.write(&i.to_le_bytes());
+	}
+
+	fn write_usize(&mut self, i: usize) {
+		self.write_u64(i as u64);
+	}
+
+	fn write_isize(&mut self, i: isize) {
+		self.write_u64((i as i64).cast_unsigned());
+	}
+
+	fn finish(&self) -> u64 {
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+}
+
+/// Hashes a key with [`StableHasher`].
+#[must_use]
+pub fn stable_hash<K: Hash + ?Sized>(key: &K) -> u64 {
+	let mut hasher = StableHasher::new();
+	key.hash(&mut hasher);
+	hasher.finish()
+}
+
+/// Maps a key to an index below `len`, evenly, or `None` if `len` is 0.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn index_for<K: Hash + ?Sized>(key: &K, len: usize) -> Option<usize> {
+	if len == 0 {
+		return None;
+	}
+	// Multiply-high spreads the hash over the range without the bias of a modulo
+	Some(((u128::from(stable_hash(key)) * len as u128) >> 64) as usize)
+}
+
+/// Maps a key to one of `colors`, or `None` if there are none.
+#[must_use]
+pub fn color_for<K: Hash + ?Sized>(key: &K, colors: &[Color]) -> Option<Color> {
+	index_for(key, colors.len()).map(|index| colors[index])
+}
+
+/// Colors to map keys to, optionally without those too close to a background.
+///
+/// Keys map onto the remaining colors, so changing the background can change every key's color,
+/// but the same colors and background always give the same mapping.
+///
+/// ```
+/// use bevy_color_palettes::{Common, hash::KeyColors};
+///
+/// let colors = KeyColors::new(&Common::all()).avoiding(Common::BLACK, 0.2);
+/// let alice = colors.get("alice").unwrap();
+/// assert_eq!(colors.get("alice"), Some(alice));
+/// assert_ne!(alice, Common::BLACK);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyColors {
+	colors: Vec<Color>,
+	candidates: Vec<Color>,
+}
+
+#[cfg(feature = "std")]
+impl KeyColors {
+	#[must_use]
+	pub fn new(colors: &[Color]) -> Self {
+		Self {
+			colors: colors.to_vec(),
+			candidates: colors.to_vec(),
+		}
+	}
+
+	/// Uses the entries of a palette.
+	#[must_use]
+	pub fn from_palette<P: Palette>() -> Self {
+		Self::new(&P::colors())
+	}
+
+	/// Leaves out colors closer than `min_distance` (Oklab) to `background`. Can be chained to
+	/// avoid several backgrounds.
+	#[must_use]
+	pub fn avoiding(mut self, background: Color, min_distance: f32) -> Self {
+		let background = Oklab::from(background);
+		self.candidates
+			.retain(|&color| Oklab::from(color).distance(background) >= min_distance);
+		self
+	}
+
+	/// Returns the color of a key, or `None` if no color is left.
+	#[must_use]
+	pub fn get<K: Hash + ?Sized>(&self, key: &K) -> Option<Color> {
+		color_for(key, &self.candidates)
+	}
+
+	/// Returns the colors keys map to, in order.
+	#[must_use]
+	pub fn candidates(&self) -> &[Color] {
+		&self.candidates
+	}
+
+	/// Returns every color, including the avoided ones.
+	#[must_use]
+	pub fn colors(&self) -> &[Color] {
+		&self.colors
+	}
+}
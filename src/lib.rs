@@ -8,7 +8,7 @@
 //! use bevy_color_palettes as pal;
 //!
 //! let transparent_white: bevy::color::Color = pal::Common::TRANSPARENT_WHITE.into();
-//! let red: bevy::color::Color = pal::aseprite::GoogleUi::RED_500.into();
+//! let red: bevy::color::Color = pal::bevy::Tailwind::RED_500.into();
 //! ```
 //!
 //! ## Creating palettes
@@ -52,24 +52,208 @@
 //! - [Dawnbringer 16](https://lospec.com/palette-list/dawnbringer-16)
 //! - [Dawnbringer 32](https://lospec.com/palette-list/dawnbringer-32)
 //!
+//! ## Palette collections
+//!
+//! [`Common`] is always available. Every other collection is behind a cargo feature, so builds
+//! only compile the palettes they ship:
+//!
+//! | feature              | palettes                                                          |
+//! | -------------------- | ----------------------------------------------------------------- |
+//! | `lospec` (default)   | `dawnbringer`, `resurrect` and `nanner`                           |
+//! | `css` (default)      | `bevy::Basic` and `bevy::Css`                                     |
+//! | `tailwind` (default) | `bevy::Tailwind`                                                  |
+//! | `google-ui`          | `google_ui::G500` and `google_ui::G500_16`                        |
+//! | `minecraft`          | `minecraft::Concrete`, `minecraft::Terracotta`, `minecraft::Wool` |
+//! | `aseprite-pixel-art` | the artist palettes in `aseprite`, e.g. `Aap64`, `Db32`, `Pico8`  |
+//! | `aseprite-retro`     | the hardware palettes in `aseprite`, e.g. `Nes`, `Cga`, `Gameboy` |
+//! | `aseprite-software`  | the software palettes in `aseprite`, e.g. `X11`, `WebSafeColors`  |
+//! | `aseprite`           | all of the `aseprite` features                                    |
+//! | `full`               | every collection                                                  |
+//!
+//! Some collections overlap: `google-ui` and `aseprite::GoogleUi`, and `minecraft` and
+//! `aseprite::Minecraft`, hold the same colors in different layouts, so enable only the one you
+//! use.
+//!
+//! Earlier releases always compiled every palette. If you use `aseprite`, `google_ui` or
+//! `minecraft` palettes, enable their feature, or `full` to keep the old behaviour:
+//!
+//! ```toml
+//! bevy-color-palettes = { version = "0.4", features = ["full"] }
+//! ```
+//!
+//! ## `no_std`
+//!
+//! The palettes, [`color::Color`] and name lookups work without the standard library. Disable
+//! the default `std` feature to use them from `no_std` code; the `alloc` feature is only needed
+//! for string parsing (`parse`) and `runtime` palettes. The color math modules, such as
+//! `oklab` and `scale`, need `std` for floating point functions.
+//!
+//! ## Generating scales
+//!
+//! `scale::generate` turns a single brand color into a UI scale like `bevy::Tailwind`
+//! (`scale::TAILWIND`, `50` to `950`) or Radix Colors (`scale::RADIX`, `1` to `12`), with
+//! lightness tuned in Oklch until the spec's step pairs meet their WCAG contrast targets.
+//! `ramp::generate` builds pixel art material ramps instead, shifting shadows toward blue and
+//! highlights toward yellow.
+//!
+//! `harmony::Harmony` generates complementary, split-complementary, analogous, triadic,
+//! tetradic and monochromatic colors from a seed, optionally snapped to a palette so accents
+//! stay within it.
+//!
+//! ## Gradients
+//!
+//! `gradient::Gradient` blends palette colors over multiple stops, in sRGB, linear sRGB, Oklab
+//! or Oklch with per-segment easing, for health bars, skies and heatmaps. Build one from a
+//! palette or ramp, sample it or cut it into discrete steps, and with the `parse` feature convert
+//! it from and to CSS `linear-gradient()`.
+//!
+//! `colormap::Colormap` has the scientific colormaps viridis, magma, inferno, plasma, cividis,
+//! turbo and a red-blue diverging map, for heatmaps and plots next to the game palettes.
+//! `cosine::CosinePalette` is the procedural `a + b·cos(2π(c·t + d))` model from shaders, and
+//! can be fitted to an existing palette.
+//!
+//! ## Remapping palettes
+//!
+//! `remap::remap` maps each color of one palette to the nearest color of another, or one-to-one
+//! with Hungarian matching, under RGB, Oklab or CIEDE2000 distance, and reports the error of
+//! every color. Use it to port sprites between palettes such as `aseprite::Db16` and
+//! `aseprite::Pico8`.
+//!
+//! ## Comparing palettes
+//!
+//! `stats::analyze` reports a palette's lightness and chroma ranges, hue histogram, closest and
+//! mean pairwise distances, near duplicates, average and most representative color, gray coverage
+//! and a hue harmony score, so candidates such as `resurrect::Resurrect64` and `aseprite::Aap64`
+//! can be compared side by side.
+//!
+//! ## Combining palettes
+//!
+//! `runtime::RuntimePalette` owns its entries, so palettes can be built and edited at runtime.
+//! The `set` operations combine them, treating colors within a ΔE threshold as equal and
+//! renaming or dropping entries whose names clash, e.g. to add brand colors to [`Common`]
+//! without duplicates.
+//!
+//! `sort::sort` reorders a palette by hue, lightness, chroma or luminance, along a Hilbert
+//! curve or along a short path between similar colors, for pickers.
+//!
+//! `diff::diff` compares two versions of a palette, reporting added, removed, renamed and
+//! modified entries, and which old colors need updating in existing art.
+//!
+//! ## Picking colors
+//!
+//! [`sample::Rng`] picks palette colors from a seed, uniformly, by weight, without replacement
+//! or as a shuffle. It needs no RNG crate and gives the same results on every platform, so
+//! procedurally generated content stays reproducible.
+//!
+//! `distinct::select` picks the K most distinguishable colors of a palette instead, optionally
+//! keeping some colors fixed and staying clear of a background, for player and team colors.
+//!
+//! `hash::KeyColors` gives IDs and names a stable palette color, such as for chat user names
+//! and debug overlays, and `cycle::ColorCycle` hands out consistent series colors for plots,
+//! adding lighter and darker variants once the palette runs out.
+//!
 //! ## What is each color?
 //!
 //! If you want to see a preview of each color, check the rustdoc for each palette. The doc for
 //! the palette will contain a grid of available colors, and each color constant will contain
-//! a color bar.
+//! a color bar. These previews come from the default `doc-previews` feature; turning it off
+//! keeps the generated docs to plain text, which makes the crate faster to compile.
+//!
+//! ## Palette color enums
+//!
+//! Alongside each palette, `palette!` generates an enum naming its entries, e.g.
+//! `dawnbringer::Dawnbringer16Color::MossGreen`. Store these in components and save files to
+//! refer to a palette entry by identity rather than by value:
+//!
+//! ```ignore
+//! use bevy_color_palettes::dawnbringer::Dawnbringer16Color;
+//!
+//! let moss = Dawnbringer16Color::MossGreen;
+//! assert_eq!(moss.name(), "moss_green");
+//! assert_eq!(moss.to_string().parse(), Ok(moss));
+//! assert_eq!(Dawnbringer16Color::from_index(moss.index()), Some(moss));
+//! let color: bevy_color_palettes::color::Color = moss.into();
+//! ```
+//!
+//! With the `serde` feature the enums serialize as their name, and with the `reflect` feature
+//! they derive bevy's `Reflect` (this needs `bevy` as a direct dependency of your crate).
+//!
+//! ## Palette tables
+//!
+//! Every palette implements [`palette::Palette`], which exposes its name and a single static
+//! table of [`palette::Entry`] values pairing each name with its generated constant.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub use macros::palette;
 
+#[cfg(any(
+	feature = "aseprite-pixel-art",
+	feature = "aseprite-retro",
+	feature = "aseprite-software"
+))]
 pub mod aseprite;
+#[cfg(any(feature = "css", feature = "tailwind"))]
 pub mod bevy;
 pub mod color;
+#[cfg(feature = "std")]
+pub mod colormap;
 mod common;
+#[cfg(feature = "std")]
+pub mod cosine;
+#[cfg(feature = "std")]
+pub mod cycle;
+#[cfg(feature = "lospec")]
 pub mod dawnbringer;
+#[cfg(feature = "std")]
+pub mod diff;
+#[cfg(feature = "std")]
+pub mod distinct;
 #[cfg(feature = "parse")]
 pub mod error;
+#[cfg(feature = "google-ui")]
 pub mod google_ui;
+#[cfg(feature = "std")]
+pub mod gradient;
+#[cfg(feature = "std")]
+pub mod harmony;
+pub mod hash;
+#[cfg(feature = "minecraft")]
 pub mod minecraft;
+#[cfg(feature = "lospec")]
 pub mod nanner;
+#[cfg(feature = "std")]
+pub mod oklab;
+pub mod palette;
+#[cfg(feature = "std")]
+pub mod ramp;
+#[cfg(feature = "std")]
+pub mod remap;
+#[cfg(feature = "lospec")]
 pub mod resurrect;
+#[cfg(feature = "alloc")]
+pub mod runtime;
+pub mod sample;
+#[cfg(feature = "std")]
+pub mod scale;
+#[cfg(feature = "std")]
+pub mod set;
+#[cfg(feature = "std")]
+pub mod sort;
+#[cfg(feature = "std")]
+pub mod stats;
+
+pub use common::{Common, CommonColor};
 
-pub use common::Common;
+#[doc(hidden)]
+pub mod __private {
+	#[cfg(feature = "reflect")]
+	pub use bevy;
+	#[cfg(feature = "serde")]
+	pub use serde;
+}
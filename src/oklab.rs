@@ -0,0 +1,238 @@
+//! The Oklab and Oklch perceptual color spaces, and WCAG contrast.
+//!
+//! Conversions from [`Color`] are exact; conversions back to [`Color`] reduce chroma at constant
+//! lightness and hue until the color fits in sRGB, then round to 8 bits per channel.
+
+use crate::color::Color;
+
+/// A color in the Oklab color space, with straight alpha.
+///
+/// `l` is the perceived lightness from 0 to 1, `a` and `b` the green-red and blue-yellow axes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab {
+	pub l: f32,
+	pub a: f32,
+	pub b: f32,
+	pub alpha: f32,
+}
+
+/// A color in the Oklch color space, the polar form of [`Oklab`].
+///
+/// `l` is the perceived lightness from 0 to 1, `c` the chroma and `h` the hue in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklch {
+	pub l: f32,
+	pub c: f32,
+	pub h: f32,
+	pub alpha: f32,
+}
+
+impl Oklab {
+	#[must_use]
+	pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+		Self { l, a, b, alpha }
+	}
+
+	/// Returns the perceptual distance to `other`, ΔE (Oklab Euclidean distance), ignoring alpha.
+	///
+	/// A difference around 0.02 is just noticeable.
+	#[must_use]
+	pub fn distance(self, other: Self) -> f32 {
+		((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+			.sqrt()
+	}
+
+	/// Interpolates from `self` to `other`, alpha included.
+	#[must_use]
+	pub fn mix(self, other: Self, t: f32) -> Self {
+		Self {
+			l: self.l + (other.l - self.l) * t,
+			a: self.a + (other.a - self.a) * t,
+			b: self.b + (other.b - self.b) * t,
+			alpha: self.alpha + (other.alpha - self.alpha) * t,
+		}
+	}
+
+	/// Returns whether the color can be shown in sRGB without clipping.
+	#[must_use]
+	pub fn in_gamut(self) -> bool {
+		const EPSILON: f32 = 1e-4;
+		self.to_linear_srgb()
+			.iter()
+			.all(|c| (-EPSILON..=1.0 + EPSILON).contains(c))
+	}
+
+	/// Converts to 8-bit sRGB, reducing chroma at constant lightness and hue until the color fits.
+	#[must_use]
+	pub fn to_color(self) -> Color {
+		let mut color = Self {
+			l: self.l.clamp(0.0, 1.0),
+			..self
+		};
+		if !color.in_gamut() {
+			let (mut low, mut high) = (0.0, 1.0);
+			for _ in 0..24 {
+				let mid = f32::midpoint(low, high);
+				if color.scale_chroma(mid).in_gamut() {
+					low = mid;
+				} else {
+					high = mid;
+				}
+			}
+			color = color.scale_chroma(low);
+		}
+
+		let [r, g, b] = color
+			.to_linear_srgb()
+			.map(|c| unit_to_u8(linear_to_srgb(c)));
+		Color::new(r, g, b, unit_to_u8(color.alpha))
+	}
+
+	fn scale_chroma(self, scale: f32) -> Self {
+		Self {
+			a: self.a * scale,
+			b: self.b * scale,
+			..self
+		}
+	}
+
+	#[allow(clippy::many_single_char_names)]
+	fn from_linear_srgb([r, g, b]: [f32; 3], alpha: f32) -> Self {
+		let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+		let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+		let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+		Self {
+			l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+			a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+			b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+			alpha,
+		}
+	}
+
+	fn to_linear_srgb(self) -> [f32; 3] {
+		let l = (self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b).powi(3);
+		let m = (self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b).powi(3);
+		let s = (self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b).powi(3);
+
+		[
+			4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+			-1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+			-0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+		]
+	}
+}
+
+impl Oklch {
+	#[must_use]
+	pub const fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+		Self { l, c, h, alpha }
+	}
+
+	/// Interpolates from `self` to `other` along the shorter hue arc, alpha included.
+	///
+	/// A gray end takes the hue of the other end, so fading to gray doesn't sweep through hues.
+	#[must_use]
+	pub fn mix(self, other: Self, t: f32) -> Self {
+		const GRAY: f32 = 1e-4;
+		let (from, to) = match (self.c < GRAY, other.c < GRAY) {
+			(true, false) => (other.h, other.h),
+			(false, true) => (self.h, self.h),
+			_ => (self.h, other.h),
+		};
+		let delta = (to - from + 540.0).rem_euclid(360.0) - 180.0;
+		Self {
+			l: self.l + (other.l - self.l) * t,
+			c: self.c + (other.c - self.c) * t,
+			h: (from + delta * t).rem_euclid(360.0),
+			alpha: self.alpha + (other.alpha - self.alpha) * t,
+		}
+	}
+
+	/// Converts to 8-bit sRGB, reducing chroma at constant lightness and hue until the color fits.
+	#[must_use]
+	pub fn to_color(self) -> Color {
+		Oklab::from(self).to_color()
+	}
+}
+
+impl From<Color> for Oklab {
+	fn from(color: Color) -> Self {
+		let [r, g, b] =
+			[color.r8, color.g8, color.b8].map(|c| srgb_to_linear(f32::from(c) / 255.0));
+		Self::from_linear_srgb([r, g, b], f32::from(color.a8) / 255.0)
+	}
+}
+
+impl From<Color> for Oklch {
+	fn from(color: Color) -> Self {
+		Oklab::from(color).into()
+	}
+}
+
+impl From<Oklab> for Oklch {
+	fn from(lab: Oklab) -> Self {
+		let c = lab.a.hypot(lab.b);
+		// Grays have no meaningful hue, keep it at 0 rather than rounding noise
+		let h = if c < 1e-4 {
+			0.0
+		} else {
+			lab.b.atan2(lab.a).to_degrees().rem_euclid(360.0)
+		};
+		Self {
+			l: lab.l,
+			c,
+			h,
+			alpha: lab.alpha,
+		}
+	}
+}
+
+impl From<Oklch> for Oklab {
+	fn from(lch: Oklch) -> Self {
+		let (sin, cos) = lch.h.to_radians().sin_cos();
+		Self {
+			l: lch.l,
+			a: lch.c * cos,
+			b: lch.c * sin,
+			alpha: lch.alpha,
+		}
+	}
+}
+
+/// Returns the WCAG 2 relative luminance of a color, from 0 for black to 1 for white.
+#[must_use]
+pub fn relative_luminance(color: Color) -> f32 {
+	let [r, g, b] = [color.r8, color.g8, color.b8].map(|c| srgb_to_linear(f32::from(c) / 255.0));
+	0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Returns the WCAG 2 contrast ratio between two colors, from 1 to 21, ignoring alpha.
+///
+/// Body text needs 4.5 (AA) or 7 (AAA), and large text and UI components need 3.
+#[must_use]
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+	let (a, b) = (relative_luminance(a), relative_luminance(b));
+	(a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+pub(crate) fn srgb_to_linear(c: f32) -> f32 {
+	if c <= 0.040_45 {
+		c / 12.92
+	} else {
+		((c + 0.055) / 1.055).powf(2.4)
+	}
+}
+
+pub(crate) fn linear_to_srgb(c: f32) -> f32 {
+	if c <= 0.003_130_8 {
+		c * 12.92
+	} else {
+		1.055 * c.powf(1.0 / 2.4) - 0.055
+	}
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn unit_to_u8(c: f32) -> u8 {
+	(c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
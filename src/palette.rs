@@ -0,0 +1,183 @@
+//! The [`Palette`] trait and helpers used by palettes generated with [`palette!`](macro@crate::palette).
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::color::Color;
+
+/// A named color in a palette's entry table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Entry {
+	pub name: &'static str,
+	pub color: Color,
+}
+
+impl Entry {
+	#[must_use]
+	pub const fn new(name: &'static str, color: Color) -> Self {
+		Self { name, color }
+	}
+}
+
+/// Implemented by every palette generated with [`palette!`](macro@crate::palette).
+///
+/// Each color is generated once, as an associated constant of the palette. [`Palette::ENTRIES`]
+/// pairs those constants with their names, and the generated `all()`, `iter()` and `get()` read
+/// from it.
+pub trait Palette {
+	/// The name of the palette, as passed to `palette!`.
+	const NAME: &'static str;
+
+	/// Every entry of the palette, in declaration order.
+	const ENTRIES: &'static [Entry];
+
+	/// Normalized entry names sorted for binary search, paired with their index in
+	/// [`Palette::ENTRIES`].
+	const LOOKUP: &'static [(&'static str, usize)];
+
+	/// Returns an entry by case and format insensitive name, if it exists in the palette.
+	#[must_use]
+	fn entry(name: &str) -> Option<&'static Entry> {
+		lookup(Self::LOOKUP, name).map(|&index| &Self::ENTRIES[index])
+	}
+
+	/// Returns the colors of the palette in declaration order, for functions that take a slice
+	/// of colors.
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	fn colors() -> Vec<Color> {
+		Self::ENTRIES.iter().map(|entry| entry.color).collect()
+	}
+}
+
+/// Copies the colors of an entry table into an array.
+///
+/// # Panics
+/// - If `entries` has fewer than `N` entries.
+#[must_use]
+pub const fn colors<const N: usize>(entries: &[Entry]) -> [Color; N] {
+	let mut colors = [Color::new(0, 0, 0, 0); N];
+	let mut i = 0;
+	while i < N {
+		colors[i] = entries[i].color;
+		i += 1;
+	}
+	colors
+}
+
+/// Returns the characters of `name` as they are compared by palette lookups.
+///
+/// Non-alphanumeric characters are skipped and ASCII letters are lowercased, so `"darkGray"`,
+/// `"dark_gray"` and `"DARK-GRAY"` all normalize to `"darkgray"`. No allocation is performed.
+pub fn normalized_chars(name: &str) -> impl Iterator<Item = char> + '_ {
+	name.chars()
+		.filter(|c| c.is_alphanumeric())
+		.map(|c| c.to_ascii_lowercase())
+}
+
+/// Compares an already-normalized key against `name`, normalizing `name` on the fly.
+#[must_use]
+pub fn compare_normalized(normalized: &str, name: &str) -> Ordering {
+	normalized.chars().cmp(normalized_chars(name))
+}
+
+/// Looks up `name` in a table sorted by normalized key, without allocating.
+///
+/// The tables generated by [`palette!`](macro@crate::palette) are sorted at compile time, so this is a
+/// binary search over the normalized keys.
+pub fn lookup<'a, T>(table: &'a [(&'static str, T)], name: &str) -> Option<&'a T> {
+	table
+		.binary_search_by(|(key, _)| compare_normalized(key, name))
+		.ok()
+		.map(|index| &table[index].1)
+}
+
+/// The error returned when parsing a palette color enum from an unknown name.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct UnknownColorName;
+
+impl core::fmt::Display for UnknownColorName {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str("unknown palette color name")
+	}
+}
+
+impl core::error::Error for UnknownColorName {}
+
+/// Adds the optional derives to a palette color enum, e.g. bevy's `Reflect`.
+#[doc(hidden)]
+#[cfg(feature = "reflect")]
+#[macro_export]
+macro_rules! __palette_enum_derive {
+	($item:item) => {
+		#[derive($crate::__private::bevy::reflect::Reflect)]
+		$item
+	};
+}
+
+/// Adds the optional derives to a palette color enum, e.g. bevy's `Reflect`.
+#[doc(hidden)]
+#[cfg(not(feature = "reflect"))]
+#[macro_export]
+macro_rules! __palette_enum_derive {
+	($item:item) => {
+		$item
+	};
+}
+
+/// Implements serde for a palette color enum, as its name.
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! __palette_enum_serde {
+	($name:ident) => {
+		impl $crate::__private::serde::Serialize for $name {
+			fn serialize<S: $crate::__private::serde::Serializer>(
+				&self,
+				serializer: S,
+			) -> ::core::result::Result<S::Ok, S::Error> {
+				serializer.serialize_str(self.name())
+			}
+		}
+
+		impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+			fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(
+				deserializer: D,
+			) -> ::core::result::Result<Self, D::Error> {
+				struct Visitor;
+
+				impl $crate::__private::serde::de::Visitor<'_> for Visitor {
+					type Value = $name;
+
+					fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+						f.write_str(concat!("a color name of ", stringify!($name)))
+					}
+
+					fn visit_str<E: $crate::__private::serde::de::Error>(
+						self,
+						name: &str,
+					) -> ::core::result::Result<$name, E> {
+						name.parse().map_err(|_| {
+							E::invalid_value(
+								$crate::__private::serde::de::Unexpected::Str(name),
+								&self,
+							)
+						})
+					}
+				}
+
+				deserializer.deserialize_str(Visitor)
+			}
+		}
+	};
+}
+
+/// Implements serde for a palette color enum, as its name.
+#[doc(hidden)]
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! __palette_enum_serde {
+	($name:ident) => {};
+}
@@ -0,0 +1,298 @@
+//! Hue-shifted ramps for pixel art materials.
+//!
+//! Pixel art palettes such as `aseprite::Aap64` and `resurrect::Resurrect64` build each material
+//! from a ramp whose shadows drift toward blue and whose highlights drift toward yellow, rather
+//! than only getting darker and lighter. [`generate`] does this procedurally in Oklch:
+//!
+//! ```
+//! use bevy_color_palettes::{color::Color, ramp};
+//!
+//! let grass = ramp::generate(Color::new(0x59, 0xc1, 0x35, 0xff), &ramp::RampSpec::default());
+//! assert_eq!(grass.len(), 5);
+//! ```
+//!
+//! [`Ramps`] goes the other way, finding the ramps inside an existing flat palette so shadows and
+//! highlights can be picked without leaving it.
+
+use alloc::vec::Vec;
+
+use crate::color::Color;
+use crate::oklab::{Oklab, Oklch};
+
+/// The Oklch hue that shadows shift toward, a cool blue.
+pub const COOL_HUE: f32 = 264.0;
+
+/// The Oklch hue that highlights shift toward, a warm yellow-orange.
+pub const WARM_HUE: f32 = 70.0;
+
+/// How to build a ramp from a base color.
+#[derive(Clone, Copy, Debug)]
+pub struct RampSpec {
+	/// The number of colors in the ramp.
+	pub steps: usize,
+	/// How far hues rotate across the whole ramp, in degrees. Shadows rotate toward [`COOL_HUE`]
+	/// and highlights toward [`WARM_HUE`], in proportion to their distance from the base color.
+	pub hue_shift: f32,
+	/// The Oklch lightness of the darkest and lightest steps.
+	pub lightness: (f32, f32),
+	/// The chroma of each step relative to the base color's, given its position from 0 (darkest)
+	/// to 1 (lightest).
+	pub saturation: fn(f32) -> f32,
+}
+
+impl Default for RampSpec {
+	/// A 5-step ramp with a 40° hue shift, from lightness 0.25 to 0.9, saturated in the middle.
+	fn default() -> Self {
+		Self {
+			steps: 5,
+			hue_shift: 40.0,
+			lightness: (0.25, 0.9),
+			saturation: saturated_middle,
+		}
+	}
+}
+
+/// A saturation curve keeping the full chroma in the middle of the ramp and 40% at the ends.
+#[must_use]
+pub fn saturated_middle(t: f32) -> f32 {
+	1.0 - 0.6 * (2.0 * t - 1.0).powi(2)
+}
+
+/// A saturation curve keeping the full chroma at every step.
+#[must_use]
+pub fn constant(_: f32) -> f32 {
+	1.0
+}
+
+/// Generates a ramp from the darkest color to the lightest.
+///
+/// Lightness is spaced evenly over [`RampSpec::lightness`]. The base color's own lightness marks
+/// where the hue is unshifted, so a dark base gives a ramp of mostly warmed highlights.
+#[must_use]
+pub fn generate(base: Color, spec: &RampSpec) -> Vec<Color> {
+	let base = Oklch::from(base);
+	let (darkest, lightest) = spec.lightness;
+	let base_t = if (lightest - darkest).abs() > f32::EPSILON {
+		((base.l - darkest) / (lightest - darkest)).clamp(0.0, 1.0)
+	} else {
+		0.5
+	};
+
+	(0..spec.steps)
+		.map(|i| {
+			#[allow(clippy::cast_precision_loss)]
+			let t = if spec.steps > 1 {
+				i as f32 / (spec.steps - 1) as f32
+			} else {
+				base_t
+			};
+			let offset = t - base_t;
+			let target = if offset < 0.0 { COOL_HUE } else { WARM_HUE };
+			Oklch {
+				l: darkest + (lightest - darkest) * t,
+				c: base.c * (spec.saturation)(t).max(0.0),
+				h: rotate_toward(base.h, target, spec.hue_shift * offset.abs()),
+				alpha: base.alpha,
+			}
+			.to_color()
+		})
+		.collect()
+}
+
+/// Rotates `hue` toward `target` along the shorter arc by up to `degrees`, without overshooting.
+fn rotate_toward(hue: f32, target: f32, degrees: f32) -> f32 {
+	let delta = (target - hue + 540.0).rem_euclid(360.0) - 180.0;
+	let step = degrees.min(delta.abs()).copysign(delta);
+	(hue + step).rem_euclid(360.0)
+}
+
+/// Limits on the neighbouring steps of a ramp, for [`Ramps::detect_with`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DetectSpec {
+	/// The largest Oklch lightness difference between neighbouring steps.
+	pub max_lightness_step: f32,
+	/// The smallest Oklch lightness difference between neighbouring steps. Steps always get
+	/// lighter, so colors of equal lightness are never neighbours even at 0.
+	pub min_lightness_step: f32,
+	/// The largest hue difference between neighbouring steps, in degrees.
+	pub max_hue_step: f32,
+	/// The largest Oklch chroma difference between neighbouring steps.
+	pub max_chroma_step: f32,
+	/// Colors below this Oklch chroma are grays, and chain regardless of hue.
+	pub gray_chroma: f32,
+}
+
+impl Default for DetectSpec {
+	fn default() -> Self {
+		Self {
+			max_lightness_step: 0.25,
+			min_lightness_step: 0.02,
+			max_hue_step: 35.0,
+			max_chroma_step: 0.12,
+			gray_chroma: 0.03,
+		}
+	}
+}
+
+/// The ramps found in a flat palette, each ordered from its darkest color to its lightest.
+///
+/// Every color of the palette belongs to exactly one ramp, possibly on its own.
+///
+/// ```
+/// use bevy_color_palettes::{color::Color, ramp::Ramps};
+///
+/// let colors = [
+///     Color::new(0x2e, 0x22, 0x2f, 0xff),
+///     Color::new(0xff, 0xff, 0xff, 0xff),
+///     Color::new(0x3e, 0x35, 0x46, 0xff),
+///     Color::new(0x62, 0x55, 0x65, 0xff),
+/// ];
+/// let ramps = Ramps::detect(&colors);
+/// assert_eq!(ramps.shade(colors[2], -1), Some(colors[0]));
+/// assert_eq!(ramps.shade(colors[2], 1), Some(colors[3]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ramps {
+	colors: Vec<Color>,
+	chains: Vec<Vec<usize>>,
+	/// The ramp and step of each color, by palette index.
+	positions: Vec<(usize, usize)>,
+}
+
+impl Ramps {
+	/// Finds the ramps of a palette with the default [`DetectSpec`].
+	#[must_use]
+	pub fn detect(colors: &[Color]) -> Self {
+		Self::detect_with(colors, &DetectSpec::default())
+	}
+
+	/// Finds the ramps of a palette.
+	///
+	/// Every pair of colors within the spec's limits is a candidate link from the darker to the
+	/// lighter one, scored by how much lightness, hue and chroma change. Links are then accepted
+	/// from best to worst, as long as neither color already has a neighbour on that side, which
+	/// splits the palette into chains. The result only depends on the colors and their order.
+	#[must_use]
+	pub fn detect_with(colors: &[Color], spec: &DetectSpec) -> Self {
+		let lch: Vec<Oklch> = colors.iter().map(|&color| Oklch::from(color)).collect();
+
+		let mut links = Vec::new();
+		for (dark, d) in lch.iter().enumerate() {
+			for (light, l) in lch.iter().enumerate() {
+				if let Some(cost) = link_cost(d, l, spec) {
+					links.push((cost, dark, light));
+				}
+			}
+		}
+		links.sort_by(|a, b| a.0.total_cmp(&b.0).then((a.1, a.2).cmp(&(b.1, b.2))));
+
+		let mut lighter = alloc::vec![None; colors.len()];
+		let mut darker = alloc::vec![None; colors.len()];
+		for (_, dark, light) in links {
+			if lighter[dark].is_none() && darker[light].is_none() {
+				lighter[dark] = Some(light);
+				darker[light] = Some(dark);
+			}
+		}
+
+		let mut ramps = Vec::new();
+		let mut positions = alloc::vec![(0, 0); colors.len()];
+		for start in (0..colors.len()).filter(|&index| darker[index].is_none()) {
+			let mut ramp = Vec::new();
+			let mut next = Some(start);
+			while let Some(index) = next {
+				positions[index] = (ramps.len(), ramp.len());
+				ramp.push(index);
+				next = lighter[index];
+			}
+			ramps.push(ramp);
+		}
+
+		Self {
+			colors: colors.to_vec(),
+			chains: ramps,
+			positions,
+		}
+	}
+
+	/// Iterates over the ramps, as palette indices from darkest to lightest.
+	pub fn iter(&self) -> impl Iterator<Item = &[usize]> + '_ {
+		self.chains.iter().map(Vec::as_slice)
+	}
+
+	/// Returns the number of ramps, counting lone colors.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.chains.len()
+	}
+
+	/// Returns whether the palette was empty.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.chains.is_empty()
+	}
+
+	/// Returns the colors of the ramp containing a palette color, from darkest to lightest.
+	///
+	/// Colors that aren't in the palette use the perceptually closest palette color.
+	#[must_use]
+	pub fn ramp_of(&self, color: Color) -> Option<Vec<Color>> {
+		let (ramp, _) = self.positions[self.closest(color)?];
+		Some(
+			self.chains[ramp]
+				.iter()
+				.map(|&index| self.colors[index])
+				.collect(),
+		)
+	}
+
+	/// Returns the color `steps` along the ramp of `color`, negative for darker and positive for
+	/// lighter, stopping at the ends of the ramp.
+	///
+	/// Colors that aren't in the palette use the perceptually closest palette color, so the result
+	/// is always a palette color. Returns `None` only for an empty palette.
+	#[must_use]
+	pub fn shade(&self, color: Color, steps: isize) -> Option<Color> {
+		let (ramp, step) = self.positions[self.closest(color)?];
+		let ramp = &self.chains[ramp];
+		let step = step.saturating_add_signed(steps).min(ramp.len() - 1);
+		Some(self.colors[ramp[step]])
+	}
+
+	/// The index of `color` in the palette, or of the closest palette color.
+	fn closest(&self, color: Color) -> Option<usize> {
+		if let Some(index) = self.colors.iter().position(|&c| c == color) {
+			return Some(index);
+		}
+		let target = Oklab::from(color);
+		self.colors
+			.iter()
+			.map(|&c| Oklab::from(c).distance(target))
+			.enumerate()
+			.min_by(|a, b| a.1.total_cmp(&b.1))
+			.map(|(index, _)| index)
+	}
+}
+
+/// Scores linking `dark` to the lighter `light` as neighbouring ramp steps, lower is better.
+fn link_cost(dark: &Oklch, light: &Oklch, spec: &DetectSpec) -> Option<f32> {
+	let dl = light.l - dark.l;
+	// Links between equally light colors could form a cycle with no darkest color to start from
+	if dl <= 0.0 || dl < spec.min_lightness_step || dl > spec.max_lightness_step {
+		return None;
+	}
+	let dc = (light.c - dark.c).abs();
+	if dc > spec.max_chroma_step {
+		return None;
+	}
+	let dh = if dark.c < spec.gray_chroma || light.c < spec.gray_chroma {
+		0.0
+	} else {
+		let delta = (light.h - dark.h).rem_euclid(360.0);
+		delta.min(360.0 - delta)
+	};
+	if dh > spec.max_hue_step {
+		return None;
+	}
+	Some(dl / spec.max_lightness_step + dh / spec.max_hue_step + dc / spec.max_chroma_step)
+}
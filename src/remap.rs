@@ -0,0 +1,329 @@
+//! Palette-to-palette remapping tables.
+//!
+//! [`remap`] maps every color of a source palette to a color of a target palette, either to the
+//! nearest one or one-to-one with Hungarian matching, and reports how far each color moved.
+//! Results only depend on the colors and their order, so remaps are reproducible.
+//!
+//! ```
+//! use bevy_color_palettes::Common;
+//! use bevy_color_palettes::remap::{Assignment, Metric, remap};
+//!
+//! let source = Common::all();
+//! let target = [Common::BLACK, Common::WHITE];
+//! let table = remap(&source, &target, Metric::Oklab, Assignment::Nearest).unwrap();
+//! assert_eq!(table.mapping[source.iter().position(|&c| c == Common::YELLOW).unwrap()], 1);
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::color::Color;
+use crate::oklab::{Oklab, srgb_to_linear};
+use crate::palette::Palette;
+
+/// How the difference between two colors is measured. Alpha is ignored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Metric {
+	/// Euclidean distance between gamma-encoded sRGB channels from 0 to 1. Cheap, but far from
+	/// how different colors look.
+	Rgb,
+	/// Euclidean distance in Oklab, ΔE OK. Around 0.02 is just noticeable.
+	#[default]
+	Oklab,
+	/// CIEDE2000 in CIELAB (D65), the print industry standard. Around 1 is just noticeable.
+	Ciede2000,
+}
+
+impl Metric {
+	/// Returns the difference between two colors.
+	#[must_use]
+	pub fn distance(self, a: Color, b: Color) -> f32 {
+		match self {
+			Metric::Rgb => {
+				let channel = |a: u8, b: u8| (f32::from(a) - f32::from(b)) / 255.0;
+				let (r, g, b) = (
+					channel(a.r8, b.r8),
+					channel(a.g8, b.g8),
+					channel(a.b8, b.b8),
+				);
+				(r * r + g * g + b * b).sqrt()
+			}
+			Metric::Oklab => Oklab::from(a).distance(Oklab::from(b)),
+			Metric::Ciede2000 => ciede2000(cielab(a), cielab(b)),
+		}
+	}
+}
+
+/// How source colors are assigned to target colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Assignment {
+	/// Each source color takes its nearest target color; several may share one.
+	#[default]
+	Nearest,
+	/// Each target color is used at most once, minimizing the total error. When the source has
+	/// more colors than the target, the sources left over take their nearest target color.
+	OneToOne,
+}
+
+/// A remapping table from source palette indices to target palette indices.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Remap {
+	/// The target index of each source color.
+	pub mapping: Vec<usize>,
+	/// The distance from each source color to its target color.
+	pub errors: Vec<f32>,
+}
+
+impl Remap {
+	/// The mean error over all source colors, or 0 for an empty source.
+	#[must_use]
+	pub fn mean_error(&self) -> f32 {
+		if self.errors.is_empty() {
+			return 0.0;
+		}
+		#[allow(clippy::cast_precision_loss)]
+		let count = self.errors.len() as f32;
+		self.errors.iter().sum::<f32>() / count
+	}
+
+	/// The largest error and the index of the source color it belongs to.
+	#[must_use]
+	pub fn max_error(&self) -> Option<(usize, f32)> {
+		self.errors
+			.iter()
+			.copied()
+			.enumerate()
+			.max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)))
+	}
+
+	/// The root mean square error over all source colors, or 0 for an empty source.
+	#[must_use]
+	pub fn rms_error(&self) -> f32 {
+		if self.errors.is_empty() {
+			return 0.0;
+		}
+		#[allow(clippy::cast_precision_loss)]
+		let count = self.errors.len() as f32;
+		(self.errors.iter().map(|e| e * e).sum::<f32>() / count).sqrt()
+	}
+
+	/// Looks up the target color of each source color.
+	///
+	/// # Panics
+	/// - If `target` is not the palette the table was built for.
+	#[must_use]
+	pub fn apply(&self, target: &[Color]) -> Vec<Color> {
+		self.mapping.iter().map(|&index| target[index]).collect()
+	}
+}
+
+/// Maps each source color to a target color.
+///
+/// Ties go to the lowest target index. Returns `None` if `target` is empty, as there is nothing
+/// to map to.
+#[must_use]
+pub fn remap(
+	source: &[Color],
+	target: &[Color],
+	metric: Metric,
+	assignment: Assignment,
+) -> Option<Remap> {
+	if target.is_empty() {
+		return None;
+	}
+	let costs: Vec<Vec<f32>> = source
+		.iter()
+		.map(|&s| target.iter().map(|&t| metric.distance(s, t)).collect())
+		.collect();
+
+	let nearest = |row: &[f32]| {
+		row.iter()
+			.enumerate()
+			.min_by(|a, b| a.1.total_cmp(b.1).then(a.0.cmp(&b.0)))
+			.map_or(0, |(index, _)| index)
+	};
+
+	let mapping: Vec<usize> = match assignment {
+		Assignment::Nearest => costs.iter().map(|row| nearest(row)).collect(),
+		Assignment::OneToOne if source.len() <= target.len() => hungarian(&costs),
+		Assignment::OneToOne => {
+			// Match every target once, then let the unmatched sources take their nearest
+			let transposed: Vec<Vec<f32>> = (0..target.len())
+				.map(|t| costs.iter().map(|row| row[t]).collect())
+				.collect();
+			let mut mapping: Vec<Option<usize>> = vec![None; source.len()];
+			for (t, s) in hungarian(&transposed).into_iter().enumerate() {
+				mapping[s] = Some(t);
+			}
+			mapping
+				.into_iter()
+				.zip(&costs)
+				.map(|(t, row)| t.unwrap_or_else(|| nearest(row)))
+				.collect()
+		}
+	};
+
+	let errors = mapping.iter().zip(&costs).map(|(&t, row)| row[t]).collect();
+	Some(Remap { mapping, errors })
+}
+
+/// Maps the entries of one palette to the entries of another, see [`remap`].
+#[must_use]
+pub fn remap_palettes<S: Palette, T: Palette>(
+	metric: Metric,
+	assignment: Assignment,
+) -> Option<Remap> {
+	remap(&S::colors(), &T::colors(), metric, assignment)
+}
+
+/// Solves the assignment problem for a cost matrix with no more rows than columns, returning
+/// the column of each row. This is the O(n²m) shortest augmenting path form of the Hungarian
+/// algorithm.
+fn hungarian(costs: &[Vec<f32>]) -> Vec<usize> {
+	let rows = costs.len();
+	let cols = costs.first().map_or(0, Vec::len);
+	// Potentials and matches are 1-based, with 0 as the virtual start column
+	let mut u = vec![0.0_f64; rows + 1];
+	let mut v = vec![0.0_f64; cols + 1];
+	let mut row_of_col = vec![0_usize; cols + 1];
+	let mut way = vec![0_usize; cols + 1];
+
+	for row in 1..=rows {
+		row_of_col[0] = row;
+		let mut col = 0;
+		let mut min_to = vec![f64::INFINITY; cols + 1];
+		let mut used = vec![false; cols + 1];
+		loop {
+			used[col] = true;
+			let current_row = row_of_col[col];
+			let mut delta = f64::INFINITY;
+			let mut next_col = 0;
+			for j in 1..=cols {
+				if used[j] {
+					continue;
+				}
+				let reduced = f64::from(costs[current_row - 1][j - 1]) - u[current_row] - v[j];
+				if reduced < min_to[j] {
+					min_to[j] = reduced;
+					way[j] = col;
+				}
+				if min_to[j] < delta {
+					delta = min_to[j];
+					next_col = j;
+				}
+			}
+			for j in 0..=cols {
+				if used[j] {
+					u[row_of_col[j]] += delta;
+					v[j] -= delta;
+				} else {
+					min_to[j] -= delta;
+				}
+			}
+			col = next_col;
+			if row_of_col[col] == 0 {
+				break;
+			}
+		}
+		while col != 0 {
+			let previous = way[col];
+			row_of_col[col] = row_of_col[previous];
+			col = previous;
+		}
+	}
+
+	let mut col_of_row = vec![0; rows];
+	for col in 1..=cols {
+		if row_of_col[col] != 0 {
+			col_of_row[row_of_col[col] - 1] = col - 1;
+		}
+	}
+	col_of_row
+}
+
+/// Converts to CIELAB with a D65 white point.
+#[allow(clippy::many_single_char_names)]
+fn cielab(color: Color) -> [f32; 3] {
+	let [r, g, b] = [color.r8, color.g8, color.b8].map(|c| srgb_to_linear(f32::from(c) / 255.0));
+	let x = (0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b) / 0.950_47;
+	let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+	let z = (0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b) / 1.088_83;
+
+	let f = |t: f32| {
+		if t > 216.0 / 24389.0 {
+			t.cbrt()
+		} else {
+			(24389.0 / 27.0 * t + 16.0) / 116.0
+		}
+	};
+	let (fx, fy, fz) = (f(x), f(y), f(z));
+	[116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// The CIEDE2000 color difference between two CIELAB colors.
+#[allow(clippy::many_single_char_names, clippy::similar_names)]
+fn ciede2000([l1, a1, b1]: [f32; 3], [l2, a2, b2]: [f32; 3]) -> f32 {
+	use core::f32::consts::PI;
+	let pow7 = |x: f32| x.powi(7);
+	let twenty_five_pow7 = 6_103_515_625.0_f32;
+
+	let c1 = a1.hypot(b1);
+	let c2 = a2.hypot(b2);
+	let c_mean = f32::midpoint(c1, c2);
+	let g = 0.5 * (1.0 - (pow7(c_mean) / (pow7(c_mean) + twenty_five_pow7)).sqrt());
+	let a1p = a1 * (1.0 + g);
+	let a2p = a2 * (1.0 + g);
+	let c1p = a1p.hypot(b1);
+	let c2p = a2p.hypot(b2);
+	let hue = |b: f32, a: f32| {
+		if a == 0.0 && b == 0.0 {
+			0.0
+		} else {
+			b.atan2(a).rem_euclid(2.0 * PI)
+		}
+	};
+	let h1p = hue(b1, a1p);
+	let h2p = hue(b2, a2p);
+
+	let dl = l2 - l1;
+	let dc = c2p - c1p;
+	let dh = if c1p * c2p == 0.0 {
+		0.0
+	} else {
+		let d = h2p - h1p;
+		if d > PI {
+			d - 2.0 * PI
+		} else if d < -PI {
+			d + 2.0 * PI
+		} else {
+			d
+		}
+	};
+	let dh_big = 2.0 * (c1p * c2p).sqrt() * (dh / 2.0).sin();
+
+	let l_mean = f32::midpoint(l1, l2);
+	let cp_mean = f32::midpoint(c1p, c2p);
+	let hp_mean = if c1p * c2p == 0.0 {
+		h1p + h2p
+	} else if (h1p - h2p).abs() <= PI {
+		f32::midpoint(h1p, h2p)
+	} else if h1p + h2p < 2.0 * PI {
+		(h1p + h2p + 2.0 * PI) / 2.0
+	} else {
+		(h1p + h2p - 2.0 * PI) / 2.0
+	};
+
+	let t = 1.0 - 0.17 * (hp_mean - 30.0_f32.to_radians()).cos()
+		+ 0.24 * (2.0 * hp_mean).cos()
+		+ 0.32 * (3.0 * hp_mean + 6.0_f32.to_radians()).cos()
+		- 0.20 * (4.0 * hp_mean - 63.0_f32.to_radians()).cos();
+	let d_theta = 30.0_f32.to_radians() * (-((hp_mean.to_degrees() - 275.0) / 25.0).powi(2)).exp();
+	let rc = 2.0 * (pow7(cp_mean) / (pow7(cp_mean) + twenty_five_pow7)).sqrt();
+	let sl = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+	let sc = 1.0 + 0.045 * cp_mean;
+	let sh = 1.0 + 0.015 * cp_mean * t;
+	let rt = -(2.0 * d_theta).sin() * rc;
+
+	let (l, c, h) = (dl / sl, dc / sc, dh_big / sh);
+	(l * l + c * c + h * h + rt * c * h).sqrt()
+}
@@ -0,0 +1,184 @@
+//! Palettes built at runtime, such as the results of `set` operations.
+//!
+//! Palettes generated with [`palette!`](macro@crate::palette) are static tables; a
+//! [`RuntimePalette`] owns its names and colors so it can be combined, reordered and extended.
+//!
+//! ```
+//! use bevy_color_palettes::{Common, color::Color, runtime::RuntimePalette};
+//!
+//! let mut palette = RuntimePalette::from_palette::<Common>();
+//! palette.push("brand", Color::new(0xff, 0x6a, 0x00, 0xff));
+//! assert_eq!(palette.get("Brand").map(|entry| entry.color), Some(Color::new(0xff, 0x6a, 0x00, 0xff)));
+//! assert_eq!(palette.get("WHITE").map(|entry| entry.color), Some(Common::WHITE));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::color::Color;
+use crate::palette::{Entry, Palette, normalized_chars};
+
+/// A named color in a [`RuntimePalette`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RuntimeEntry {
+	pub name: String,
+	pub color: Color,
+}
+
+impl RuntimeEntry {
+	#[must_use]
+	pub fn new(name: impl Into<String>, color: Color) -> Self {
+		Self {
+			name: name.into(),
+			color,
+		}
+	}
+}
+
+impl From<&Entry> for RuntimeEntry {
+	fn from(entry: &Entry) -> Self {
+		Self::new(entry.name, entry.color)
+	}
+}
+
+/// An owned, ordered list of named colors.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RuntimePalette {
+	name: String,
+	entries: Vec<RuntimeEntry>,
+}
+
+impl RuntimePalette {
+	/// Creates an empty palette.
+	#[must_use]
+	pub fn new(name: impl Into<String>) -> Self {
+		Self {
+			name: name.into(),
+			entries: Vec::new(),
+		}
+	}
+
+	/// Copies the entries of a generated palette, keeping its name.
+	#[must_use]
+	pub fn from_palette<P: Palette>() -> Self {
+		Self::from_entries(P::NAME, P::ENTRIES)
+	}
+
+	/// Copies a table of entries.
+	#[must_use]
+	pub fn from_entries(name: impl Into<String>, entries: &[Entry]) -> Self {
+		Self {
+			name: name.into(),
+			entries: entries.iter().map(RuntimeEntry::from).collect(),
+		}
+	}
+
+	/// Names unnamed colors after their hex code, e.g. `color_ff6a00`, with the alpha appended
+	/// when it isn't opaque.
+	#[must_use]
+	pub fn from_colors(name: impl Into<String>, colors: &[Color]) -> Self {
+		Self {
+			name: name.into(),
+			entries: colors
+				.iter()
+				.map(|&color| RuntimeEntry::new(hex_name(color), color))
+				.collect(),
+		}
+	}
+
+	/// The name of the palette.
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Renames the palette.
+	#[must_use]
+	pub fn with_name(self, name: impl Into<String>) -> Self {
+		Self {
+			name: name.into(),
+			..self
+		}
+	}
+
+	/// Appends an entry. Names aren't checked for duplicates; [`RuntimePalette::get`] returns the
+	/// first match.
+	pub fn push(&mut self, name: impl Into<String>, color: Color) {
+		self.entries.push(RuntimeEntry::new(name, color));
+	}
+
+	/// Returns an entry by case and format insensitive name, like [`Palette::entry`].
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<&RuntimeEntry> {
+		self.position(name).map(|index| &self.entries[index])
+	}
+
+	/// Returns the index of an entry by case and format insensitive name.
+	#[must_use]
+	pub fn position(&self, name: &str) -> Option<usize> {
+		self.entries
+			.iter()
+			.position(|entry| normalized_chars(&entry.name).eq(normalized_chars(name)))
+	}
+
+	/// Every entry, in order.
+	#[must_use]
+	pub fn entries(&self) -> &[RuntimeEntry] {
+		&self.entries
+	}
+
+	/// Every entry, in order, for editing in place.
+	#[must_use]
+	pub fn entries_mut(&mut self) -> &mut Vec<RuntimeEntry> {
+		&mut self.entries
+	}
+
+	/// Copies the colors, in order.
+	#[must_use]
+	pub fn colors(&self) -> Vec<Color> {
+		self.entries.iter().map(|entry| entry.color).collect()
+	}
+
+	/// Iterates over the entries, in order.
+	pub fn iter(&self) -> core::slice::Iter<'_, RuntimeEntry> {
+		self.entries.iter()
+	}
+
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.entries.len()
+	}
+
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.entries.is_empty()
+	}
+}
+
+impl<'a> IntoIterator for &'a RuntimePalette {
+	type Item = &'a RuntimeEntry;
+	type IntoIter = core::slice::Iter<'a, RuntimeEntry>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.entries.iter()
+	}
+}
+
+impl Extend<RuntimeEntry> for RuntimePalette {
+	fn extend<I: IntoIterator<Item = RuntimeEntry>>(&mut self, iter: I) {
+		self.entries.extend(iter);
+	}
+}
+
+/// The name given to unnamed colors, e.g. `color_ff6a00`.
+pub(crate) fn hex_name(color: Color) -> String {
+	use core::fmt::Write;
+
+	let mut name = String::from("color_");
+	let Color { r8, g8, b8, a8, .. } = color;
+	let _ = write!(name, "{r8:02x}{g8:02x}{b8:02x}");
+	if a8 != 0xff {
+		let _ = write!(name, "{a8:02x}");
+	}
+	name
+}
@@ -0,0 +1,175 @@
+//! Deterministic random picks from palettes.
+//!
+//! [`Rng`] is a small seeded generator (`SplitMix64`) with integer-only state updates, so the same
+//! seed gives the same picks on every platform and in every version of this crate, without an
+//! RNG dependency. It isn't suitable for cryptography.
+//!
+//! ```
+//! use bevy_color_palettes::{Common, sample::Rng};
+//!
+//! let mut rng = Rng::new(42);
+//! let shirt = rng.pick(Common::all().as_slice()).copied();
+//! assert_eq!(shirt, Rng::new(42).pick(Common::all().as_slice()).copied());
+//!
+//! let foliage = [Common::GREEN, Common::YELLOW, Common::RED];
+//! let leaf = rng.pick_weighted(&foliage, &[8.0, 1.5, 0.5]);
+//! assert!(leaf.is_some());
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::palette::{Entry, Palette};
+
+/// A seeded `SplitMix64` generator.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rng {
+	state: u64,
+}
+
+impl Rng {
+	#[must_use]
+	pub const fn new(seed: u64) -> Self {
+		Self { state: seed }
+	}
+
+	/// Returns the next 64 random bits.
+	pub fn next_u64(&mut self) -> u64 {
+		self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+
+	/// Returns a uniform number from 0 (inclusive) to 1 (exclusive), with 24 bits of precision.
+	#[allow(clippy::cast_precision_loss)]
+	pub fn next_f32(&mut self) -> f32 {
+		(self.next_u64() >> 40) as f32 / (1_u32 << 24) as f32
+	}
+
+	/// Returns a uniform index below `bound`, without modulo bias.
+	///
+	/// # Panics
+	/// - If `bound` is 0.
+	#[allow(clippy::cast_possible_truncation)]
+	pub fn below(&mut self, bound: usize) -> usize {
+		assert!(bound > 0, "bound must be positive");
+		// Lemire's multiply-and-reject method
+		let bound = bound as u64;
+		let threshold = bound.wrapping_neg() % bound;
+		loop {
+			let product = u128::from(self.next_u64()) * u128::from(bound);
+			if product as u64 >= threshold {
+				return (product >> 64) as usize;
+			}
+		}
+	}
+
+	/// Picks an item uniformly, or `None` if there are none.
+	pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+		if items.is_empty() {
+			None
+		} else {
+			Some(&items[self.below(items.len())])
+		}
+	}
+
+	/// Picks an entry of a palette uniformly.
+	///
+	/// # Panics
+	/// - If the palette has no entries.
+	pub fn pick_entry<P: Palette>(&mut self) -> &'static Entry {
+		self.pick(P::ENTRIES).expect("palette has no entries")
+	}
+
+	/// Picks an item with a probability proportional to its weight, or `None` if no item has a
+	/// positive weight.
+	///
+	/// Weights pair up with items by index; missing, negative and NaN weights count as 0.
+	pub fn pick_weighted<'a, T>(&mut self, items: &'a [T], weights: &[f32]) -> Option<&'a T> {
+		let weight = |index: usize| weights.get(index).copied().filter(|w| *w > 0.0);
+		let total: f32 = (0..items.len()).filter_map(weight).sum();
+		if total.is_nan() || total <= 0.0 || total.is_infinite() {
+			return None;
+		}
+
+		let target = self.next_f32() * total;
+		let mut sum = 0.0;
+		let mut last = None;
+		for index in 0..items.len() {
+			let Some(w) = weight(index) else {
+				continue;
+			};
+			sum += w;
+			last = Some(index);
+			if target < sum {
+				break;
+			}
+		}
+		// Rounding can leave the target past the last sum, which then goes to the last item
+		last.map(|index| &items[index])
+	}
+
+	/// Shuffles items in place with a Fisher-Yates shuffle.
+	pub fn shuffle<T>(&mut self, items: &mut [T]) {
+		for i in (1..items.len()).rev() {
+			items.swap(i, self.below(i + 1));
+		}
+	}
+
+	/// Returns a shuffled copy of the items.
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub fn shuffled<T: Clone>(&mut self, items: &[T]) -> Vec<T> {
+		let mut items = items.to_vec();
+		self.shuffle(&mut items);
+		items
+	}
+
+	/// Picks `count` items uniformly, with replacement. Returns an empty list if there are no
+	/// items.
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub fn sample<T: Clone>(&mut self, items: &[T], count: usize) -> Vec<T> {
+		if items.is_empty() {
+			return Vec::new();
+		}
+		(0..count)
+			.map(|_| items[self.below(items.len())].clone())
+			.collect()
+	}
+
+	/// Picks `count` items with probabilities proportional to their weights, with replacement,
+	/// see [`Rng::pick_weighted`]. Returns an empty list if no item has a positive weight.
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub fn sample_weighted<T: Clone>(
+		&mut self,
+		items: &[T],
+		weights: &[f32],
+		count: usize,
+	) -> Vec<T> {
+		(0..count)
+			.map_while(|_| self.pick_weighted(items, weights).cloned())
+			.collect()
+	}
+
+	/// Picks `count` distinct items uniformly, without replacement, in the order they were
+	/// picked. Returns every item, shuffled, if there are fewer than `count`.
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub fn sample_distinct<T: Clone>(&mut self, items: &[T], count: usize) -> Vec<T> {
+		let mut indices: Vec<usize> = (0..items.len()).collect();
+		let count = count.min(items.len());
+		// A partial Fisher-Yates shuffle from the front
+		for i in 0..count {
+			let j = i + self.below(items.len() - i);
+			indices.swap(i, j);
+		}
+		indices[..count]
+			.iter()
+			.map(|&index| items[index].clone())
+			.collect()
+	}
+}
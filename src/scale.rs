@@ -0,0 +1,225 @@
+//! Accessible UI color scales generated from a single seed color.
+//!
+//! A [`ScaleSpec`] describes the lightness and relative chroma of each step in Oklch, and the
+//! step pairs that must reach a WCAG contrast ratio. [`generate`] keeps the seed's hue, places the
+//! seed on a step, and then darkens or lightens steps until every contrast target is met.
+//!
+//! ```
+//! use bevy_color_palettes::{color::Color, oklab::contrast_ratio, scale};
+//!
+//! let brand = scale::generate(Color::new(0x3b, 0x82, 0xf6, 0xff), &scale::TAILWIND);
+//! let (background, text) = (brand.get(50).unwrap(), brand.get(700).unwrap());
+//! assert!(contrast_ratio(background, text) >= 4.5);
+//! ```
+
+use crate::color::Color;
+use crate::oklab::{Oklch, contrast_ratio};
+
+/// A contrast ratio that two steps of a scale must reach, by index.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContrastTarget {
+	pub lighter: usize,
+	pub darker: usize,
+	pub ratio: f32,
+}
+
+impl ContrastTarget {
+	#[must_use]
+	pub const fn new(lighter: usize, darker: usize, ratio: f32) -> Self {
+		Self {
+			lighter,
+			darker,
+			ratio,
+		}
+	}
+}
+
+/// The shape of a scale, from its lightest step to its darkest.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScaleSpec<const N: usize> {
+	/// The label of each step, e.g. `50` to `950`.
+	pub labels: [u16; N],
+	/// The Oklch lightness of each step.
+	pub lightness: [f32; N],
+	/// The chroma of each step, relative to the seed's.
+	pub chroma: [f32; N],
+	/// The step the seed is placed on, or `None` for the step closest to its lightness.
+	pub anchor: Option<usize>,
+	/// The contrast ratios to reach, in order of priority.
+	pub contrast: &'static [ContrastTarget],
+}
+
+/// An 11-step scale labelled `50` to `950`, shaped like `bevy::Tailwind`.
+///
+/// Step `50` reaches 4.5:1 against `600` and 7:1 against `900`, `100` reaches 4.5:1 against
+/// `700`, and `50` reaches 3:1 against `500`.
+pub const TAILWIND: ScaleSpec<11> = ScaleSpec {
+	labels: [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950],
+	lightness: [
+		0.971, 0.936, 0.885, 0.808, 0.704, 0.637, 0.577, 0.505, 0.444, 0.396, 0.258,
+	],
+	chroma: [
+		0.055, 0.135, 0.26, 0.48, 0.8, 1.0, 1.03, 0.9, 0.75, 0.6, 0.39,
+	],
+	anchor: None,
+	contrast: &[
+		ContrastTarget::new(0, 6, 4.5),
+		ContrastTarget::new(1, 7, 4.5),
+		ContrastTarget::new(0, 9, 7.0),
+		ContrastTarget::new(0, 5, 3.0),
+	],
+};
+
+/// A 12-step scale labelled `1` to `12`, shaped like Radix Colors.
+///
+/// The seed is step `9`, the solid background. Steps `1` and `2` are app backgrounds, `3` to `5`
+/// component backgrounds, `6` to `8` borders, and `11` and `12` text: `11` reaches 4.5:1 and `12`
+/// reaches 7:1 against step `2`.
+pub const RADIX: ScaleSpec<12> = ScaleSpec {
+	labels: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+	lightness: [
+		0.993, 0.982, 0.956, 0.931, 0.899, 0.859, 0.806, 0.738, 0.649, 0.615, 0.55, 0.33,
+	],
+	chroma: [
+		0.02, 0.06, 0.15, 0.25, 0.35, 0.45, 0.55, 0.7, 1.0, 1.0, 0.85, 0.5,
+	],
+	anchor: Some(8),
+	contrast: &[
+		ContrastTarget::new(1, 10, 4.5),
+		ContrastTarget::new(1, 11, 7.0),
+	],
+};
+
+/// A generated scale of `N` colors, from its lightest step to its darkest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scale<const N: usize> {
+	pub labels: [u16; N],
+	pub colors: [Color; N],
+}
+
+impl<const N: usize> Scale<N> {
+	/// Returns the color of a step by label, e.g. `500`.
+	#[must_use]
+	pub fn get(&self, label: u16) -> Option<Color> {
+		self.labels
+			.iter()
+			.position(|&l| l == label)
+			.map(|index| self.colors[index])
+	}
+
+	/// Iterates over `(label, color)` pairs, from lightest to darkest.
+	pub fn iter(&self) -> impl Iterator<Item = (u16, Color)> + '_ {
+		self.labels.iter().copied().zip(self.colors.iter().copied())
+	}
+}
+
+/// The smallest lightness difference kept between neighbouring steps.
+const MIN_STEP: f32 = 0.005;
+
+/// Generates a scale from a seed color.
+///
+/// The seed's hue is kept for every step and its chroma is scaled by [`ScaleSpec::chroma`]. The
+/// seed itself is placed unchanged on the anchor step, and lightness is kept strictly decreasing
+/// from step to step. Each contrast target then darkens its darker step, or lightens its lighter
+/// step if that is the anchor or darkening is not enough, until the ratio is reached.
+///
+/// # Panics
+/// - If a contrast target refers to a step outside the scale.
+#[must_use]
+pub fn generate<const N: usize>(seed: Color, spec: &ScaleSpec<N>) -> Scale<N> {
+	let seed_lch = Oklch::from(seed);
+	let anchor = spec.anchor.unwrap_or_else(|| {
+		(0..N)
+			.min_by(|&a, &b| {
+				let da = (spec.lightness[a] - seed_lch.l).abs();
+				let db = (spec.lightness[b] - seed_lch.l).abs();
+				da.total_cmp(&db)
+			})
+			.unwrap_or(0)
+	});
+
+	// The base curve, bent so the seed's lightness fits at the anchor
+	let mut lightness = spec.lightness;
+	if anchor < N {
+		lightness[anchor] = seed_lch.l;
+		for i in (anchor + 1)..N {
+			lightness[i] = lightness[i].min(lightness[i - 1] - MIN_STEP).max(0.0);
+		}
+		for i in (0..anchor).rev() {
+			lightness[i] = lightness[i].max(lightness[i + 1] + MIN_STEP).min(1.0);
+		}
+	}
+
+	let step = |index: usize, l: f32| {
+		if index == anchor {
+			seed
+		} else {
+			Oklch::new(
+				l,
+				seed_lch.c * spec.chroma[index],
+				seed_lch.h,
+				seed_lch.alpha,
+			)
+			.to_color()
+		}
+	};
+	let mut colors: [Color; N] = core::array::from_fn(|i| step(i, lightness[i]));
+
+	for target in spec.contrast {
+		let (lighter, darker) = (target.lighter, target.darker);
+		if contrast_ratio(colors[lighter], colors[darker]) >= target.ratio {
+			continue;
+		}
+
+		if darker != anchor {
+			let l = search_lightness(lightness[darker], 0.0, |l| {
+				contrast_ratio(colors[lighter], step(darker, l)) >= target.ratio
+			});
+			lightness[darker] = l;
+			colors[darker] = step(darker, l);
+		}
+		if lighter != anchor && contrast_ratio(colors[lighter], colors[darker]) < target.ratio {
+			let l = search_lightness(lightness[lighter], 1.0, |l| {
+				contrast_ratio(step(lighter, l), colors[darker]) >= target.ratio
+			});
+			lightness[lighter] = l;
+			colors[lighter] = step(lighter, l);
+		}
+
+		// Keep the steps beyond the moved ones in order
+		for i in (darker + 1)..N {
+			if i != anchor && lightness[i] > lightness[i - 1] - MIN_STEP {
+				lightness[i] = (lightness[i - 1] - MIN_STEP).max(0.0);
+				colors[i] = step(i, lightness[i]);
+			}
+		}
+		for i in (0..lighter).rev() {
+			if i != anchor && lightness[i] < lightness[i + 1] + MIN_STEP {
+				lightness[i] = (lightness[i + 1] + MIN_STEP).min(1.0);
+				colors[i] = step(i, lightness[i]);
+			}
+		}
+	}
+
+	Scale {
+		labels: spec.labels,
+		colors,
+	}
+}
+
+/// Finds the lightness closest to `from`, towards `to`, that satisfies `ok`.
+fn search_lightness(from: f32, to: f32, ok: impl Fn(f32) -> bool) -> f32 {
+	if !ok(to) {
+		return to;
+	}
+	let (mut bad, mut good) = (from, to);
+	for _ in 0..24 {
+		let mid = f32::midpoint(bad, good);
+		if ok(mid) {
+			good = mid;
+		} else {
+			bad = mid;
+		}
+	}
+	good
+}
@@ -0,0 +1,174 @@
+//! Set operations on palettes that treat perceptually close colors as equal.
+//!
+//! Colors match when their Oklab distance is at most [`SetOptions::threshold`], so near
+//! duplicates such as `#ff0000` and `#fe0101` collapse into one entry. The results are
+//! [`RuntimePalette`]s named after the first operand, with unique names.
+//!
+//! ```
+//! use bevy_color_palettes::{Common, color::Color, runtime::RuntimePalette, set};
+//!
+//! let common = RuntimePalette::from_palette::<Common>();
+//! let mut brand = RuntimePalette::new("Brand");
+//! brand.push("red", Color::new(0xfe, 0x01, 0x01, 0xff));
+//! brand.push("orange", Color::new(0xff, 0x6a, 0x00, 0xff));
+//!
+//! let combined = set::union(&common, &brand, &set::SetOptions::default());
+//! assert_eq!(combined.len(), common.len() + 1);
+//! assert!(combined.get("orange").is_some());
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::color::Color;
+use crate::oklab::Oklab;
+use crate::runtime::{RuntimeEntry, RuntimePalette};
+
+/// What to do when an entry's name is already taken by an entry of a different color.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NameConflict {
+	/// Adds the entry under its name with the first free `_2`, `_3`, ... suffix.
+	#[default]
+	Rename,
+	/// Keeps the existing entry and drops the new one.
+	KeepFirst,
+	/// Gives the existing entry the new entry's color.
+	KeepLast,
+}
+
+/// Options for the set operations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SetOptions {
+	/// Colors at most this Oklab distance apart are equal, 0 for exact matches only.
+	pub threshold: f32,
+	/// How to resolve entries with the same normalized name but different colors.
+	pub conflict: NameConflict,
+}
+
+impl Default for SetOptions {
+	/// Colors within 0.02, a just noticeable difference, are equal, and name conflicts are renamed.
+	fn default() -> Self {
+		Self {
+			threshold: 0.02,
+			conflict: NameConflict::Rename,
+		}
+	}
+}
+
+impl SetOptions {
+	/// Returns whether two colors are equal under the threshold. Alpha must match exactly.
+	#[must_use]
+	pub fn matches(&self, a: Color, b: Color) -> bool {
+		a == b || (a.a8 == b.a8 && Oklab::from(a).distance(Oklab::from(b)) <= self.threshold)
+	}
+}
+
+/// Returns the entries of `a`, then those of `b`, skipping any color that matches an earlier one.
+#[must_use]
+pub fn union(a: &RuntimePalette, b: &RuntimePalette, options: &SetOptions) -> RuntimePalette {
+	let mut result = RuntimePalette::new(a.name());
+	for entry in a.iter().chain(b) {
+		insert(&mut result, entry, *options);
+	}
+	result
+}
+
+/// Returns the entries of `a` whose color matches a color of `b`, without duplicates.
+#[must_use]
+pub fn intersection(
+	a: &RuntimePalette,
+	b: &RuntimePalette,
+	options: &SetOptions,
+) -> RuntimePalette {
+	let mut result = RuntimePalette::new(a.name());
+	for entry in a.iter().filter(|entry| contains(b, entry.color, *options)) {
+		insert(&mut result, entry, *options);
+	}
+	result
+}
+
+/// Returns the entries of `a` whose color matches no color of `b`, without duplicates.
+#[must_use]
+pub fn difference(a: &RuntimePalette, b: &RuntimePalette, options: &SetOptions) -> RuntimePalette {
+	let mut result = RuntimePalette::new(a.name());
+	for entry in a.iter().filter(|entry| !contains(b, entry.color, *options)) {
+		insert(&mut result, entry, *options);
+	}
+	result
+}
+
+/// Overlays `b` onto `a`, for applying overrides such as brand colors.
+///
+/// Entries of `b` replace the color of the entry of `a` with the same name, whatever
+/// [`SetOptions::conflict`] says; the rest are added like in [`union`]. Colors of `a` that an
+/// override now duplicates are dropped.
+#[must_use]
+pub fn merge(a: &RuntimePalette, b: &RuntimePalette, options: &SetOptions) -> RuntimePalette {
+	let overrides: Vec<&RuntimeEntry> = b
+		.iter()
+		.filter(|entry| a.position(&entry.name).is_some())
+		.collect();
+
+	let mut result = RuntimePalette::new(a.name());
+	for entry in a {
+		if let Some(by) = b.get(&entry.name) {
+			if result.position(&entry.name).is_none() {
+				result.push(&*entry.name, by.color);
+			}
+		} else if !overrides
+			.iter()
+			.any(|by| options.matches(by.color, entry.color))
+		{
+			insert(&mut result, entry, *options);
+		}
+	}
+	for entry in b.iter().filter(|entry| a.position(&entry.name).is_none()) {
+		insert(&mut result, entry, *options);
+	}
+	result
+}
+
+/// Returns `palette` without colors that match an earlier entry.
+#[must_use]
+pub fn dedup(palette: &RuntimePalette, options: &SetOptions) -> RuntimePalette {
+	let mut result = RuntimePalette::new(palette.name());
+	for entry in palette {
+		insert(&mut result, entry, *options);
+	}
+	result
+}
+
+fn contains(palette: &RuntimePalette, color: Color, options: SetOptions) -> bool {
+	palette
+		.iter()
+		.any(|entry| options.matches(entry.color, color))
+}
+
+/// Adds `entry` unless its color is already in `result`, resolving name conflicts.
+fn insert(result: &mut RuntimePalette, entry: &RuntimeEntry, options: SetOptions) {
+	if contains(result, entry.color, options) {
+		return;
+	}
+	let Some(existing) = result.position(&entry.name) else {
+		result.push(&*entry.name, entry.color);
+		return;
+	};
+	match options.conflict {
+		NameConflict::Rename => {
+			let name = free_name(result, &entry.name);
+			result.push(name, entry.color);
+		}
+		NameConflict::KeepFirst => {}
+		NameConflict::KeepLast => result.entries_mut()[existing].color = entry.color,
+	}
+}
+
+/// The first of `name_2`, `name_3`, ... that isn't taken.
+fn free_name(palette: &RuntimePalette, name: &str) -> String {
+	// One of the first `len + 1` suffixes is always free
+	(2..=palette.len() + 2)
+		.map(|suffix| format!("{name}_{suffix}"))
+		.find(|candidate| palette.position(candidate).is_none())
+		.unwrap_or_default()
+}
@@ -0,0 +1,196 @@
+//! Reordering palettes for pickers and previews.
+//!
+//! Many palettes, such as `aseprite::X11` and `aseprite::WebSafeColors`, list their colors in an
+//! order that looks random. [`sort`] reorders a palette by a single property, or along a path
+//! through color space that keeps similar colors together:
+//!
+//! ```
+//! use bevy_color_palettes::Common;
+//! use bevy_color_palettes::sort::{SortBy, sort_palette};
+//!
+//! let by_lightness = sort_palette::<Common>(SortBy::Lightness);
+//! assert_eq!(by_lightness.entries()[0].color, Common::BLACK);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::color::Color;
+use crate::oklab::{Oklab, Oklch, relative_luminance};
+use crate::palette::Palette;
+use crate::runtime::RuntimePalette;
+
+/// Colors below this Oklch chroma have no meaningful hue, and sort before the others by hue.
+const GRAY_CHROMA: f32 = 0.03;
+
+/// How to order colors. Ties keep the original order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SortBy {
+	/// Grays from dark to light, then the other colors around the Oklch hue wheel from red,
+	/// dark to light within the same hue.
+	#[default]
+	Hue,
+	/// Dark to light by Oklch lightness.
+	Lightness,
+	/// Gray to saturated by Oklch chroma.
+	Chroma,
+	/// Dark to light by WCAG relative luminance, how much light the color emits.
+	Luminance,
+	/// Along a 3D Hilbert curve through Oklab, which keeps nearby colors close in the order
+	/// without favouring one property.
+	Hilbert,
+	/// A path from the darkest color that visits every color, shortened so neighbouring colors
+	/// differ as little as possible: each step goes to the nearest unvisited color, then
+	/// crossings are undone with 2-opt.
+	NearestNeighbor,
+}
+
+/// Returns the order of `colors` as indices, so that `order[0]` is the index of the first color.
+#[must_use]
+pub fn order(colors: &[Color], by: SortBy) -> Vec<usize> {
+	let mut indices: Vec<usize> = (0..colors.len()).collect();
+	let lch: Vec<Oklch> = colors.iter().map(|&color| Oklch::from(color)).collect();
+	match by {
+		SortBy::Hue => indices.sort_by(|&a, &b| {
+			let key = |c: &Oklch| {
+				(
+					c.c >= GRAY_CHROMA,
+					if c.c >= GRAY_CHROMA { c.h } else { 0.0 },
+				)
+			};
+			let (ka, kb) = (key(&lch[a]), key(&lch[b]));
+			ka.0.cmp(&kb.0)
+				.then(ka.1.total_cmp(&kb.1))
+				.then(lch[a].l.total_cmp(&lch[b].l))
+		}),
+		SortBy::Lightness => indices.sort_by(|&a, &b| lch[a].l.total_cmp(&lch[b].l)),
+		SortBy::Chroma => indices.sort_by(|&a, &b| lch[a].c.total_cmp(&lch[b].c)),
+		SortBy::Luminance => indices.sort_by(|&a, &b| {
+			relative_luminance(colors[a]).total_cmp(&relative_luminance(colors[b]))
+		}),
+		SortBy::Hilbert => indices.sort_by_cached_key(|&index| hilbert_index(colors[index])),
+		SortBy::NearestNeighbor => indices = nearest_neighbor_path(colors, &lch),
+	}
+	indices
+}
+
+/// Returns a palette with the same entries in a new order.
+#[must_use]
+pub fn sort(palette: &RuntimePalette, by: SortBy) -> RuntimePalette {
+	let mut sorted = RuntimePalette::new(palette.name());
+	let entries = palette.entries();
+	sorted.extend(
+		order(&palette.colors(), by)
+			.into_iter()
+			.map(|index| entries[index].clone()),
+	);
+	sorted
+}
+
+/// Returns the entries of a palette in a new order.
+#[must_use]
+pub fn sort_palette<P: Palette>(by: SortBy) -> RuntimePalette {
+	sort(&RuntimePalette::from_palette::<P>(), by)
+}
+
+/// The position of a color along a Hilbert curve through an 8-bit grid over Oklab.
+fn hilbert_index(color: Color) -> u32 {
+	const BITS: u32 = 8;
+	let lab = Oklab::from(color);
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	let quantize = |value: f32, min: f32, max: f32| {
+		(((value - min) / (max - min)).clamp(0.0, 1.0) * 255.0).round() as u32
+	};
+	// sRGB spans roughly ±0.32 on both Oklab color axes
+	let mut x = [
+		quantize(lab.l, 0.0, 1.0),
+		quantize(lab.a, -0.33, 0.33),
+		quantize(lab.b, -0.33, 0.33),
+	];
+
+	// Skilling's transform from axes to the transposed Hilbert index, "Programming the Hilbert
+	// curve" (2004)
+	let mut q = 1 << (BITS - 1);
+	while q > 1 {
+		let p = q - 1;
+		for i in 0..x.len() {
+			if x[i] & q == 0 {
+				let t = (x[0] ^ x[i]) & p;
+				x[0] ^= t;
+				x[i] ^= t;
+			} else {
+				x[0] ^= p;
+			}
+		}
+		q >>= 1;
+	}
+	for i in 1..x.len() {
+		x[i] ^= x[i - 1];
+	}
+	let mut t = 0;
+	let mut q = 1 << (BITS - 1);
+	while q > 1 {
+		if x[x.len() - 1] & q != 0 {
+			t ^= q - 1;
+		}
+		q >>= 1;
+	}
+	for axis in &mut x {
+		*axis ^= t;
+	}
+
+	// Interleave the transposed bits, most significant first
+	let mut index = 0;
+	for bit in (0..BITS).rev() {
+		for axis in x {
+			index = (index << 1) | ((axis >> bit) & 1);
+		}
+	}
+	index
+}
+
+/// A short path through every color, from the darkest.
+fn nearest_neighbor_path(colors: &[Color], lch: &[Oklch]) -> Vec<usize> {
+	let n = colors.len();
+	let Some(start) = (0..n).min_by(|&a, &b| lch[a].l.total_cmp(&lch[b].l)) else {
+		return Vec::new();
+	};
+	let lab: Vec<Oklab> = colors.iter().map(|&color| Oklab::from(color)).collect();
+	let distance = |a: usize, b: usize| lab[a].distance(lab[b]);
+
+	let mut path = alloc::vec![start];
+	let mut visited = alloc::vec![false; n];
+	visited[start] = true;
+	while path.len() < n {
+		let last = path[path.len() - 1];
+		let next = (0..n)
+			.filter(|&index| !visited[index])
+			.min_by(|&a, &b| distance(last, a).total_cmp(&distance(last, b)))
+			.unwrap_or(last);
+		visited[next] = true;
+		path.push(next);
+	}
+
+	// 2-opt on an open path: reversing path[i + 1..=j] replaces the edges (i, i + 1) and
+	// (j, j + 1) with (i, j) and (i + 1, j + 1), where a missing j + 1 costs nothing. The start
+	// stays fixed. Passes are capped since each one is quadratic.
+	for _ in 0..n.min(64) {
+		let mut improved = false;
+		for i in 0..n.saturating_sub(2) {
+			for j in i + 2..n {
+				let next = |k: usize| path.get(k + 1).copied();
+				let before = distance(path[i], path[i + 1])
+					+ next(j).map_or(0.0, |after| distance(path[j], after));
+				let after = distance(path[i], path[j])
+					+ next(j).map_or(0.0, |after| distance(path[i + 1], after));
+				if after < before - 1e-6 {
+					path[i + 1..=j].reverse();
+					improved = true;
+				}
+			}
+		}
+		if !improved {
+			break;
+		}
+	}
+	path
+}
@@ -0,0 +1,278 @@
+//! Palette statistics for comparing candidate palettes.
+//!
+//! [`analyze`] measures a palette in Oklab and Oklch: its lightness and chroma ranges, how its
+//! hues spread, how close its colors get to each other, its average color, how well its grays
+//! cover the lightness range, and how closely its hues follow a classic harmony template. The
+//! [`Display`](core::fmt::Display) output is a short report:
+//!
+//! ```
+//! use bevy_color_palettes::{Common, stats};
+//!
+//! let report = stats::analyze(&Common::all());
+//! assert_eq!(report.count, Common::len());
+//! println!("{report}");
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::color::Color;
+use crate::oklab::{Oklab, Oklch};
+use crate::palette::Palette;
+
+/// The number of hue bins in [`PaletteStats::hue_histogram`], 30° each.
+pub const HUE_BINS: usize = 12;
+
+/// The number of lightness bins [`PaletteStats::gray_coverage`] counts.
+pub const GRAY_BINS: usize = 10;
+
+/// Thresholds used by [`analyze_with`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StatsOptions {
+	/// Pairs closer than this Oklab distance are near duplicates.
+	pub near_duplicate: f32,
+	/// Colors below this Oklch chroma are grays, without a meaningful hue.
+	pub gray_chroma: f32,
+}
+
+impl Default for StatsOptions {
+	/// Near duplicates under 0.03, about one and a half just noticeable differences, and grays
+	/// under a chroma of 0.03.
+	fn default() -> Self {
+		Self {
+			near_duplicate: 0.03,
+			gray_chroma: 0.03,
+		}
+	}
+}
+
+/// Statistics of a palette, see [`analyze`]. Distances are Oklab Euclidean distances.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaletteStats {
+	/// The number of colors.
+	pub count: usize,
+	/// The smallest and largest Oklch lightness.
+	pub lightness: (f32, f32),
+	/// The smallest and largest Oklch chroma.
+	pub chroma: (f32, f32),
+	/// The number of non-gray colors in each 30° Oklch hue bin, starting at 0°.
+	pub hue_histogram: [usize; HUE_BINS],
+	/// The number of gray colors, left out of the hue histogram.
+	pub gray_count: usize,
+	/// The closest pair of colors, by palette index, and their distance.
+	pub min_distance: Option<(usize, usize, f32)>,
+	/// The mean distance over all pairs of colors.
+	pub mean_distance: f32,
+	/// Every pair of colors closer than [`StatsOptions::near_duplicate`], closest first.
+	pub near_duplicates: Vec<(usize, usize, f32)>,
+	/// The mean of all colors in Oklab.
+	pub average: Color,
+	/// The index of the color with the smallest total distance to the others, the one that best
+	/// represents the palette as a whole.
+	pub dominant: Option<usize>,
+	/// The fraction of the lightness range, in [`GRAY_BINS`] bins, that has a gray.
+	pub gray_coverage: f32,
+	/// How closely the hues fit the best matching harmony template, from 0 to 1, weighted by
+	/// chroma. Palettes without chromatic colors score 1.
+	///
+	/// The templates are Matsuda's hue templates as used by Cohen-Or et al., *Color Harmonization*
+	/// (2006): a single narrow or wide sector, two opposite sectors, a narrow and a wide sector
+	/// at 90°, and half of the hue wheel.
+	pub harmony: f32,
+}
+
+/// Analyzes a palette with the default [`StatsOptions`].
+#[must_use]
+pub fn analyze(colors: &[Color]) -> PaletteStats {
+	analyze_with(colors, &StatsOptions::default())
+}
+
+/// Analyzes the entries of a palette with the default [`StatsOptions`].
+#[must_use]
+pub fn analyze_palette<P: Palette>() -> PaletteStats {
+	analyze(&P::colors())
+}
+
+/// Analyzes a palette.
+#[must_use]
+pub fn analyze_with(colors: &[Color], options: &StatsOptions) -> PaletteStats {
+	let lab: Vec<Oklab> = colors.iter().map(|&color| Oklab::from(color)).collect();
+	let lch: Vec<Oklch> = lab.iter().map(|&lab| Oklch::from(lab)).collect();
+
+	let range = |values: &mut dyn Iterator<Item = f32>| {
+		values.fold(None, |range: Option<(f32, f32)>, value| {
+			Some(range.map_or((value, value), |(min, max)| {
+				(min.min(value), max.max(value))
+			}))
+		})
+	};
+	let lightness = range(&mut lch.iter().map(|c| c.l)).unwrap_or_default();
+	let chroma = range(&mut lch.iter().map(|c| c.c)).unwrap_or_default();
+
+	let mut hue_histogram = [0; HUE_BINS];
+	let mut gray_bins = [false; GRAY_BINS];
+	let mut gray_count = 0;
+	for color in &lch {
+		if color.c < options.gray_chroma {
+			gray_count += 1;
+			gray_bins[bin(color.l, GRAY_BINS)] = true;
+		} else {
+			hue_histogram[bin(color.h / 360.0, HUE_BINS)] += 1;
+		}
+	}
+	#[allow(clippy::cast_precision_loss)]
+	let gray_coverage = gray_bins.iter().filter(|&&covered| covered).count() as f32 / GRAY_BINS as f32;
+
+	let mut pairs = Vec::new();
+	let mut totals = alloc::vec![0.0_f32; colors.len()];
+	for i in 0..lab.len() {
+		for j in (i + 1)..lab.len() {
+			let distance = lab[i].distance(lab[j]);
+			totals[i] += distance;
+			totals[j] += distance;
+			pairs.push((i, j, distance));
+		}
+	}
+	pairs.sort_by(|a, b| a.2.total_cmp(&b.2).then((a.0, a.1).cmp(&(b.0, b.1))));
+	#[allow(clippy::cast_precision_loss)]
+	let mean_distance = if pairs.is_empty() {
+		0.0
+	} else {
+		pairs.iter().map(|pair| pair.2).sum::<f32>() / pairs.len() as f32
+	};
+	let min_distance = pairs.first().copied();
+	let near_duplicates = pairs
+		.iter()
+		.copied()
+		.take_while(|pair| pair.2 < options.near_duplicate)
+		.collect();
+
+	let dominant = totals
+		.iter()
+		.enumerate()
+		.min_by(|a, b| a.1.total_cmp(b.1).then(a.0.cmp(&b.0)))
+		.map(|(index, _)| index);
+
+	#[allow(clippy::cast_precision_loss)]
+	let average = if lab.is_empty() {
+		Color::new(0, 0, 0, 0)
+	} else {
+		let n = lab.len() as f32;
+		let sum = lab.iter().fold(Oklab::new(0.0, 0.0, 0.0, 0.0), |sum, c| {
+			Oklab::new(sum.l + c.l, sum.a + c.a, sum.b + c.b, sum.alpha + c.alpha)
+		});
+		Oklab::new(sum.l / n, sum.a / n, sum.b / n, sum.alpha / n).to_color()
+	};
+
+	let chromatic: Vec<(f32, f32)> = lch
+		.iter()
+		.filter(|c| c.c >= options.gray_chroma)
+		.map(|c| (c.h, c.c))
+		.collect();
+
+	PaletteStats {
+		count: colors.len(),
+		lightness,
+		chroma,
+		hue_histogram,
+		gray_count,
+		min_distance,
+		mean_distance,
+		near_duplicates,
+		average,
+		dominant,
+		gray_coverage,
+		harmony: harmony(&chromatic),
+	}
+}
+
+/// The bin of a value from 0 to 1.
+#[allow(
+	clippy::cast_possible_truncation,
+	clippy::cast_sign_loss,
+	clippy::cast_precision_loss
+)]
+fn bin(value: f32, bins: usize) -> usize {
+	((value * bins as f32) as usize).min(bins - 1)
+}
+
+/// Matsuda's hue templates, as sectors of `(center offset, width)` in degrees.
+const TEMPLATES: [&[(f32, f32)]; 7] = [
+	&[(0.0, 18.0)],
+	&[(0.0, 93.6)],
+	&[(0.0, 18.0), (90.0, 79.2)],
+	&[(0.0, 18.0), (180.0, 18.0)],
+	&[(0.0, 180.0)],
+	&[(0.0, 93.6), (180.0, 18.0)],
+	&[(0.0, 93.6), (180.0, 93.6)],
+];
+
+/// Scores how well `(hue, chroma)` pairs fit the best template at its best rotation.
+fn harmony(hues: &[(f32, f32)]) -> f32 {
+	let total_chroma: f32 = hues.iter().map(|&(_, c)| c).sum();
+	if total_chroma <= 0.0 {
+		return 1.0;
+	}
+
+	let mut best = f32::INFINITY;
+	for template in TEMPLATES {
+		for rotation in 0..360_u16 {
+			let rotation = f32::from(rotation);
+			let mut cost = 0.0;
+			for &(hue, chroma) in hues {
+				let outside = template
+					.iter()
+					.map(|&(center, width)| {
+						let delta = (hue - rotation - center).rem_euclid(360.0);
+						(delta.min(360.0 - delta) - width / 2.0).max(0.0)
+					})
+					.fold(f32::INFINITY, f32::min);
+				cost += outside * chroma;
+				if cost >= best {
+					break;
+				}
+			}
+			best = best.min(cost);
+		}
+	}
+
+	// A hue can be at most 90° outside the half-wheel template, so this stays within 0 to 1
+	(1.0 - best / (total_chroma * 90.0)).clamp(0.0, 1.0)
+}
+
+impl fmt::Display for PaletteStats {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let Color { r8, g8, b8, .. } = self.average;
+		writeln!(f, "colors:          {}", self.count)?;
+		writeln!(
+			f,
+			"lightness:       {:.3} to {:.3}",
+			self.lightness.0, self.lightness.1
+		)?;
+		writeln!(
+			f,
+			"chroma:          {:.3} to {:.3}",
+			self.chroma.0, self.chroma.1
+		)?;
+		write!(f, "hues (30°):     ")?;
+		for count in self.hue_histogram {
+			write!(f, " {count}")?;
+		}
+		writeln!(f, " + {} grays", self.gray_count)?;
+		match self.min_distance {
+			Some((i, j, distance)) => {
+				writeln!(f, "min distance:    {distance:.4} (#{i} and #{j})")?;
+			}
+			None => writeln!(f, "min distance:    -")?,
+		}
+		writeln!(f, "mean distance:   {:.4}", self.mean_distance)?;
+		writeln!(f, "near duplicates: {}", self.near_duplicates.len())?;
+		writeln!(f, "average:         #{r8:02x}{g8:02x}{b8:02x}")?;
+		match self.dominant {
+			Some(index) => writeln!(f, "dominant:        #{index}")?,
+			None => writeln!(f, "dominant:        -")?,
+		}
+		writeln!(f, "gray coverage:   {:.0}%", self.gray_coverage * 100.0)?;
+		write!(f, "harmony:         {:.3}", self.harmony)
+	}
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::colormap::{Colormap, Kind};
+use bevy_color_palettes::oklab::Oklch;
+
+fn close(a: Color, b: Color, tolerance: u8) -> bool {
+	a.r8.abs_diff(b.r8) <= tolerance
+		&& a.g8.abs_diff(b.g8) <= tolerance
+		&& a.b8.abs_diff(b.b8) <= tolerance
+}
+
+#[test]
+fn test_reference_values() {
+	// matplotlib's values halfway between two reference colors
+	let viridis = Colormap::VIRIDIS.sample(0.5);
+	assert!(
+		close(viridis, Color::new(0x21, 0x90, 0x8d, 0xff), 4),
+		"{viridis:?}"
+	);
+	let magma = Colormap::MAGMA.sample(0.5);
+	assert!(
+		close(magma, Color::new(0xb7, 0x37, 0x79, 0xff), 4),
+		"{magma:?}"
+	);
+
+	for map in Colormap::ALL {
+		assert_eq!(map.sample(0.0), map.stops()[0], "{}", map.name());
+		assert_eq!(
+			map.sample(1.0),
+			*map.stops().last().unwrap(),
+			"{}",
+			map.name()
+		);
+		assert_eq!(map.sample(-1.0), map.sample(0.0));
+		assert_eq!(map.sample(2.0), map.sample(1.0));
+	}
+}
+
+#[test]
+fn test_sequential_lightness() {
+	for map in [
+		Colormap::VIRIDIS,
+		Colormap::MAGMA,
+		Colormap::INFERNO,
+		Colormap::PLASMA,
+		Colormap::CIVIDIS,
+	] {
+		assert_eq!(map.kind(), Kind::Sequential);
+		let lightness: Vec<f32> = map.steps(64).iter().map(|&c| Oklch::from(c).l).collect();
+		assert!(
+			lightness.windows(2).all(|pair| pair[0] < pair[1] + 1e-3),
+			"{}",
+			map.name()
+		);
+	}
+
+	let rd_bu = Colormap::RD_BU;
+	assert_eq!(rd_bu.kind(), Kind::Diverging);
+	let middle = Oklch::from(rd_bu.sample(0.5));
+	assert!(middle.l > 0.95 && middle.c < 0.01);
+}
+
+#[test]
+fn test_reversed() {
+	let map = Colormap::PLASMA.reversed();
+	assert!(map.is_reversed());
+	assert_eq!(map.sample(0.0), Colormap::PLASMA.sample(1.0));
+	assert_eq!(map.sample(0.3), Colormap::PLASMA.sample(0.7));
+	assert_eq!(map.reversed(), Colormap::PLASMA);
+	assert_eq!(map.to_gradient().sample(0.0), Colormap::PLASMA.sample(1.0));
+}
+
+#[test]
+fn test_steps_and_palette() {
+	assert!(Colormap::TURBO.steps(0).is_empty());
+	assert_eq!(Colormap::TURBO.steps(1), [Colormap::TURBO.sample(0.5)]);
+
+	let palette = Colormap::CIVIDIS.to_palette(3);
+	assert_eq!(palette.name(), "cividis");
+	let names: Vec<&str> = palette.iter().map(|entry| entry.name.as_str()).collect();
+	assert_eq!(names, ["cividis_0", "cividis_1", "cividis_2"]);
+	assert_eq!(palette.colors(), Colormap::CIVIDIS.steps(3));
+
+	let gradient = Colormap::VIRIDIS.to_gradient();
+	for t in [0.0, 0.2, 0.5, 0.9] {
+		assert_eq!(gradient.sample(t), Colormap::VIRIDIS.sample(t));
+	}
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::cosine::CosinePalette;
+
+const PRESETS: [CosinePalette; 4] = [
+	CosinePalette::RAINBOW,
+	CosinePalette::DUSK,
+	CosinePalette::EMBER,
+	CosinePalette::NEON,
+];
+
+#[test]
+fn test_sample() {
+	let rainbow = CosinePalette::RAINBOW;
+	assert_eq!(rainbow.sample(0.0), Color::new(0xff, 0x42, 0x42, 0xff));
+	assert_eq!(rainbow.sample(0.0), rainbow.sample(1.0));
+	assert_eq!(rainbow.steps(3).len(), 3);
+	assert!(rainbow.steps(0).is_empty());
+
+	let gray = CosinePalette::new([0.5; 3], [0.0; 3], [1.0; 3], [0.0; 3]);
+	assert_eq!(gray.sample(0.3), Color::new(0x80, 0x80, 0x80, 0xff));
+	let clipped = CosinePalette::new([1.0; 3], [1.0; 3], [0.0; 3], [0.0; 3]);
+	assert_eq!(clipped.sample(0.0), Color::new(0xff, 0xff, 0xff, 0xff));
+}
+
+#[test]
+fn test_fit_presets() {
+	for preset in PRESETS {
+		let colors = preset.steps(16);
+		let fitted = CosinePalette::fit(&colors).unwrap();
+		assert!(fitted.rms_error(&colors) < 0.01, "{preset:?} -> {fitted:?}");
+		assert!(preset.rms_error(&colors) < 0.005);
+	}
+}
+
+#[test]
+fn test_fit_edge_cases() {
+	assert_eq!(CosinePalette::fit(&[]), None);
+
+	let single = Color::new(0x20, 0x40, 0x80, 0xff);
+	let fitted = CosinePalette::fit(&[single]).unwrap();
+	assert_eq!(fitted.sample(0.0), single);
+	assert_eq!(fitted.sample(0.7), single);
+
+	let ramp = [
+		Color::new(0x10, 0x10, 0x10, 0xff),
+		Color::new(0x80, 0x80, 0x80, 0xff),
+		Color::new(0xf0, 0xf0, 0xf0, 0xff),
+	];
+	let fitted = CosinePalette::fit(&ramp).unwrap();
+	assert!(fitted.rms_error(&ramp) < 0.02, "{fitted:?}");
+}
+
+#[cfg(feature = "lospec")]
+#[test]
+fn test_fit_palette() {
+	use bevy_color_palettes::resurrect::Resurrect64;
+
+	let fitted = CosinePalette::fit_palette::<Resurrect64>().unwrap();
+	assert!(fitted.rms_error(&Resurrect64::all()) < 0.4);
+}
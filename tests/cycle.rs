@@ -0,0 +1,77 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::Common;
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::cycle::ColorCycle;
+use bevy_color_palettes::oklab::{Oklab, Oklch};
+
+const BASE: [Color; 3] = [Common::RED, Common::GREEN, Common::BLUE];
+
+#[test]
+fn test_cycle() {
+	let cycle = ColorCycle::new(&BASE);
+	let colors: Vec<Color> = cycle.iter().take(cycle.len() + 3).collect();
+	assert_eq!(&colors[..3], BASE);
+	assert_eq!(&colors[cycle.len()..], BASE);
+	for (n, &color) in colors.iter().enumerate() {
+		assert_eq!(cycle.get(n), color);
+	}
+
+	// The first variant round is lighter
+	for (variant, base) in colors[3..6].iter().zip(BASE) {
+		assert!(Oklch::from(*variant).l > Oklch::from(base).l + 0.1);
+	}
+
+	// Every color of the cycle is distinct
+	let distinct = &colors[..cycle.len()];
+	for (i, a) in distinct.iter().enumerate() {
+		for b in &distinct[i + 1..] {
+			assert!(Oklab::from(*a).distance(Oklab::from(*b)) >= 0.02);
+		}
+	}
+}
+
+#[test]
+fn test_avoiding() {
+	let cycle = ColorCycle::new(&[Common::BLACK, Common::RED, Common::WHITE])
+		.avoiding(Common::BLACK, 0.2)
+		.avoiding(Common::WHITE, 0.2);
+	assert_eq!(cycle.get(0), Common::RED);
+	for color in cycle.iter().take(cycle.len()) {
+		let lab = Oklab::from(color);
+		assert!(lab.distance(Oklab::from(Common::BLACK)) >= 0.2);
+		assert!(lab.distance(Oklab::from(Common::WHITE)) >= 0.2);
+	}
+
+	// Avoided colors don't come back as variants, so the cycle is the same as without them
+	let dark = ColorCycle::new(&[Common::BLACK, Common::RED]).avoiding(Common::BLACK, 0.1);
+	let lighter = Oklch {
+		l: Oklch::from(Common::BLACK).l + 0.15,
+		..Oklch::from(Common::BLACK)
+	}
+	.to_color();
+	assert!(Oklab::from(lighter).distance(Oklab::from(Common::BLACK)) >= 0.1);
+	assert!(dark.iter().take(dark.len()).all(|color| color != lighter));
+	let red: Vec<Color> = ColorCycle::new(&[Common::RED])
+		.avoiding(Common::BLACK, 0.1)
+		.iter()
+		.take(dark.len() + 1)
+		.collect();
+	assert_eq!(dark.iter().take(dark.len() + 1).collect::<Vec<_>>(), red);
+
+	let empty = ColorCycle::new(&[Common::BLACK]).avoiding(Common::BLACK, 0.5);
+	assert!(empty.is_empty());
+	assert_eq!(empty.iter().next(), None);
+	assert_eq!(empty.get(3), Color::new(0, 0, 0, 0));
+}
+
+#[test]
+fn test_variants() {
+	let plain = ColorCycle::new(&BASE).with_variants(&[]);
+	assert_eq!(plain.len(), 3);
+	assert_eq!(plain.get(4), BASE[1]);
+
+	let palette = ColorCycle::from_palette::<Common>();
+	assert_eq!(palette.get(0), Common::all()[0]);
+	assert!(palette.len() > Common::len());
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::diff::{Change, diff};
+use bevy_color_palettes::runtime::RuntimePalette;
+
+const GRASS: Color = Color::new(0x59, 0xc1, 0x35, 0xff);
+const DARK_GRASS: Color = Color::new(0x4a, 0xb0, 0x2e, 0xff);
+const SKY: Color = Color::new(0x4d, 0x9b, 0xe6, 0xff);
+const LAVA: Color = Color::new(0xe8, 0x3b, 0x3b, 0xff);
+const SAND: Color = Color::new(0xf9, 0xc2, 0x2b, 0xff);
+
+fn palette(entries: &[(&str, Color)]) -> RuntimePalette {
+	let mut palette = RuntimePalette::new("Game");
+	for &(name, color) in entries {
+		palette.push(name, color);
+	}
+	palette
+}
+
+#[test]
+fn test_unchanged() {
+	let old = palette(&[("grass", GRASS), ("sky", SKY)]);
+	let new = palette(&[("Sky", SKY), ("GRASS", GRASS)]);
+	let changes = diff(&old, &new);
+	assert!(changes.is_empty());
+	assert_eq!(changes.to_string(), "");
+}
+
+#[test]
+fn test_changes() {
+	let old = palette(&[("grass", GRASS), ("lava", LAVA), ("sky", SKY)]);
+	let new = palette(&[("water", SKY), ("grass", DARK_GRASS), ("sand", SAND)]);
+	let changes = diff(&old, &new);
+
+	assert_eq!(changes.changes.len(), 4);
+	assert!(matches!(
+		&changes.changes[0],
+		Change::Modified { name, from: GRASS, to: DARK_GRASS, delta_e } if name == "grass" && *delta_e > 0.02
+	));
+	assert_eq!(
+		changes.changes[1],
+		Change::Removed {
+			name: "lava".into(),
+			color: LAVA
+		}
+	);
+	assert_eq!(
+		changes.changes[2],
+		Change::Renamed {
+			from: "sky".into(),
+			to: "water".into(),
+			color: SKY
+		}
+	);
+	assert_eq!(
+		changes.changes[3],
+		Change::Added {
+			name: "sand".into(),
+			color: SAND
+		}
+	);
+	assert_eq!(changes.added().count(), 1);
+	assert_eq!(changes.removed().count(), 1);
+	assert_eq!(changes.renamed().count(), 1);
+	assert_eq!(changes.modified().count(), 1);
+
+	assert_eq!(changes.affected_colors(), [GRASS, LAVA]);
+	assert!(changes.affects(&[SKY, LAVA]));
+	assert!(!changes.affects(&[SKY, SAND]));
+
+	let report = changes.to_string();
+	assert!(report.starts_with("~ grass #59c135 -> #4ab02e (ΔE "));
+	assert!(report.ends_with("- lava #e83b3b\n> sky -> water #4d9be6\n+ sand #f9c22b\n"));
+}
+
+#[test]
+fn test_alpha_in_report() {
+	let old = palette(&[]);
+	let new = palette(&[("glass", SKY.with_alpha_u8(0x80))]);
+	assert_eq!(diff(&old, &new).to_string(), "+ glass #4d9be680\n");
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::Common;
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::distinct::{DistinctOptions, select};
+use bevy_color_palettes::remap::Metric;
+
+/// The smallest pairwise distance of a selection, checked independently of `select`.
+fn min_pairwise(colors: &[Color], indices: &[usize]) -> f32 {
+	let mut min = f32::INFINITY;
+	for (i, &a) in indices.iter().enumerate() {
+		for &b in &indices[i + 1..] {
+			min = min.min(Metric::Oklab.distance(colors[a], colors[b]));
+		}
+	}
+	min
+}
+
+#[test]
+fn test_select() {
+	let colors = Common::all();
+	let two = select(&colors, 2, &DistinctOptions::default());
+	let mut pair: Vec<Color> = two.indices.iter().map(|&i| colors[i]).collect();
+	pair.sort_by_key(|c| c.r8);
+	assert!(pair.contains(&Common::BLACK) || pair.contains(&Common::TRANSPARENT_BLACK));
+
+	for count in 3..=colors.len() {
+		let selection = select(&colors, count, &DistinctOptions::default());
+		assert_eq!(selection.indices.len(), count);
+		let min = min_pairwise(&colors, &selection.indices);
+		assert!((selection.min_distance.unwrap() - min).abs() < 1e-6);
+	}
+
+	assert_eq!(
+		select(&colors, 20, &DistinctOptions::default())
+			.indices
+			.len(),
+		colors.len()
+	);
+	assert_eq!(
+		select(&colors, 1, &DistinctOptions::default()).min_distance,
+		None
+	);
+	assert!(
+		select(&[], 3, &DistinctOptions::default())
+			.indices
+			.is_empty()
+	);
+}
+
+#[test]
+fn test_fixed_and_avoid() {
+	let colors = Common::all();
+	let red = colors.iter().position(|&c| c == Common::RED).unwrap();
+	let options = DistinctOptions {
+		fixed: &[red],
+		avoid: &[Common::WHITE],
+		avoid_distance: 0.3,
+		..DistinctOptions::default()
+	};
+	let selection = select(&colors, 4, &options);
+	assert_eq!(selection.indices[0], red);
+	assert_eq!(selection.indices.len(), 4);
+	for &index in &selection.indices {
+		assert!(Metric::Oklab.distance(colors[index], Common::WHITE) >= 0.3);
+		assert!(
+			selection.min_distance.unwrap() <= Metric::Oklab.distance(colors[index], Common::WHITE)
+		);
+	}
+}
+
+#[cfg(all(feature = "aseprite-pixel-art", feature = "css"))]
+#[test]
+fn test_player_colors() {
+	use bevy_color_palettes::aseprite::Pico8;
+	use bevy_color_palettes::bevy::Css;
+
+	for colors in [Pico8::all().to_vec(), Css::all().to_vec()] {
+		let options = DistinctOptions {
+			avoid: &[Color::new(0x1d, 0x2b, 0x53, 0xff)],
+			avoid_distance: 0.1,
+			..DistinctOptions::default()
+		};
+		let players = select(&colors, 6, &options);
+		assert_eq!(players.indices.len(), 6);
+		assert!(players.min_distance.unwrap() > 0.15);
+	}
+}
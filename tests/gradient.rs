@@ -0,0 +1,117 @@
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use bevy_color_palettes::Common;
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::gradient::{Easing, Gradient, Space};
+
+#[test]
+fn test_sample_and_steps() {
+	let gradient = Gradient::new(Space::Srgb)
+		.stop(1.0, Common::WHITE)
+		.stop(0.0, Common::BLACK);
+	assert_eq!(gradient.sample(-1.0), Common::BLACK);
+	assert_eq!(gradient.sample(2.0), Common::WHITE);
+	assert_eq!(gradient.sample(0.5), Color::new(128, 128, 128, 255));
+	assert_eq!(
+		gradient.steps(3),
+		[Common::BLACK, Color::new(128, 128, 128, 255), Common::WHITE]
+	);
+	assert!(gradient.steps(0).is_empty());
+	assert_eq!(gradient.sample(f32::NAN), Common::BLACK);
+	assert_eq!(Gradient::default().sample(0.5), Color::new(0, 0, 0, 0));
+}
+
+#[test]
+fn test_spaces_and_easing() {
+	let red_to_blue = |space| {
+		Gradient::new(space)
+			.stop(0.0, Common::RED)
+			.stop(1.0, Common::BLUE)
+	};
+
+	let linear = red_to_blue(Space::LinearSrgb).sample(0.5);
+	let srgb = red_to_blue(Space::Srgb).sample(0.5);
+	assert!(linear.r8 > srgb.r8 && linear.b8 > srgb.b8);
+	assert_ne!(red_to_blue(Space::Oklab).sample(0.5), srgb);
+	assert_ne!(
+		red_to_blue(Space::Oklch).sample(0.5),
+		red_to_blue(Space::Oklab).sample(0.5)
+	);
+
+	let stepped = Gradient::new(Space::Oklab)
+		.stop(0.0, Common::RED)
+		.eased_stop(0.5, Common::GREEN, Easing::Step)
+		.eased_stop(1.0, Common::BLUE, Easing::EaseIn);
+	assert_eq!(stepped.sample(0.49), Common::RED);
+	assert_eq!(stepped.sample(0.5), Common::GREEN);
+	let eased = stepped.sample(0.75);
+	let linear = Space::Oklab.mix(Common::GREEN, Common::BLUE, 0.5);
+	assert!(eased.g8 > linear.g8);
+	assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+}
+
+#[test]
+fn test_from_palette() {
+	let gradient = Gradient::from_palette::<Common>(Space::Srgb);
+	assert_eq!(gradient.stops.len(), Common::len());
+	assert_eq!(gradient.sample(0.0), Common::all()[0]);
+	assert_eq!(gradient.sample(1.0), Common::all()[Common::len() - 1]);
+}
+
+#[cfg(feature = "parse")]
+#[test]
+fn test_css() {
+	let gradient = Gradient::parse_css(
+		"linear-gradient(to right in oklch, #ff0000, rgb(0 0 255 / 50%) 40% 60%, #00ff00)",
+	)
+	.unwrap();
+	assert_eq!(gradient.angle, 90.0);
+	assert_eq!(gradient.space, Space::Oklch);
+	let positions: Vec<f32> = gradient.stops.iter().map(|stop| stop.position).collect();
+	assert_eq!(positions, [0.0, 0.4, 0.6, 1.0]);
+	assert_eq!(gradient.stops[1].color, Color::new(0, 0, 255, 128));
+	assert_eq!(Gradient::parse_css(&gradient.to_css()).unwrap(), gradient);
+
+	let filled =
+		Gradient::parse_css("linear-gradient(#000, #111, #222 50%, #333, #444, #555)").unwrap();
+	let positions: Vec<f32> = filled.stops.iter().map(|stop| stop.position).collect();
+	assert_eq!(positions, [0.0, 0.25, 0.5, 0.666_666_7, 0.833_333_4, 1.0]);
+	assert_eq!(filled.space, Space::Srgb);
+	assert_eq!(filled.angle, 180.0);
+
+	// Stops outside 0% to 100% keep the visible part of the gradient
+	let overflowing = Gradient::parse_css("linear-gradient(#000 -100%, #fff 100%)").unwrap();
+	let positions: Vec<f32> = overflowing.stops.iter().map(|stop| stop.position).collect();
+	assert_eq!(positions, [0.0, 1.0]);
+	assert_eq!(overflowing.stops[0].color, Color::new(128, 128, 128, 255));
+	assert_ne!(
+		overflowing,
+		Gradient::parse_css("linear-gradient(#000 0%, #fff 100%)").unwrap()
+	);
+	let inside = Gradient::parse_css("linear-gradient(#000 -50%, #888 50%, #fff 150%)").unwrap();
+	let positions: Vec<f32> = inside.stops.iter().map(|stop| stop.position).collect();
+	assert_eq!(positions, [0.0, 0.5, 1.0]);
+	assert_eq!(
+		inside.stops[0].color,
+		Space::Srgb.mix(Common::BLACK, Color::new(0x88, 0x88, 0x88, 0xff), 0.5)
+	);
+	assert_eq!(Gradient::parse_css(&inside.to_css()).unwrap(), inside);
+	let past_end = Gradient::parse_css("linear-gradient(#000 120%, #fff 150%)").unwrap();
+	assert_eq!(past_end.sample(0.0), Common::BLACK);
+	assert_eq!(past_end.sample(1.0), Common::BLACK);
+
+	assert!(Gradient::parse_css("radial-gradient(#000, #fff)").is_err());
+	assert!(Gradient::parse_css("linear-gradient(#000)").is_err());
+	assert!(Gradient::parse_css("linear-gradient(in hsl, #000, #fff)").is_err());
+}
+
+#[cfg(all(feature = "parse", feature = "css"))]
+#[test]
+fn test_css_color_names() {
+	let gradient =
+		Gradient::parse_css("linear-gradient(45deg, red, transparent 50%, moccasin)").unwrap();
+	assert_eq!(gradient.stops[0].color, Color::new(255, 0, 0, 255));
+	assert_eq!(gradient.stops[1].color, Color::new(0, 0, 0, 0));
+	assert_eq!(gradient.angle, 45.0);
+}
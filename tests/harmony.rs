@@ -0,0 +1,71 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::Common;
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::harmony::Harmony;
+use bevy_color_palettes::oklab::Oklch;
+use bevy_color_palettes::remap::{Assignment, Metric};
+
+const SEED: Color = Color::new(0x3a, 0x7d, 0x44, 0xff);
+
+fn hue_distance(a: f32, b: f32) -> f32 {
+	let delta = (a - b).rem_euclid(360.0);
+	delta.min(360.0 - delta)
+}
+
+#[test]
+fn test_hue_harmonies() {
+	let seed = Oklch::from(SEED);
+	for harmony in Harmony::ALL {
+		let colors = harmony.generate(SEED);
+		assert_eq!(colors[0], SEED, "{harmony:?}");
+		if harmony == Harmony::Monochromatic {
+			continue;
+		}
+		assert_eq!(colors.len(), harmony.hue_offsets().len() + 1);
+		for (color, offset) in colors[1..].iter().zip(harmony.hue_offsets()) {
+			let color = Oklch::from(*color);
+			assert!((color.l - seed.l).abs() < 0.01, "{harmony:?}");
+			assert!(
+				hue_distance(color.h, seed.h + offset) < 3.0,
+				"{harmony:?}: {} vs {}",
+				color.h,
+				seed.h + offset
+			);
+		}
+	}
+}
+
+#[test]
+fn test_monochromatic() {
+	let seed = Oklch::from(SEED);
+	let colors = Harmony::Monochromatic.generate(SEED);
+	assert_eq!(colors.len(), 5);
+	let lightness: Vec<f32> = colors[1..].iter().map(|&c| Oklch::from(c).l).collect();
+	assert!(lightness.windows(2).all(|pair| pair[0] < pair[1]));
+	for color in &colors[1..] {
+		let color = Oklch::from(*color);
+		assert!((color.l - seed.l).abs() > 0.05);
+		assert!(hue_distance(color.h, seed.h) < 3.0);
+	}
+}
+
+#[test]
+fn test_snapped() {
+	let palette = Common::all();
+	for harmony in Harmony::ALL {
+		let nearest = harmony.generate_in(SEED, &palette, Metric::Oklab, Assignment::Nearest);
+		assert_eq!(nearest.len(), harmony.generate(SEED).len());
+		assert!(nearest.iter().all(|color| palette.contains(color)));
+
+		let distinct = harmony.generate_in(SEED, &palette, Metric::Oklab, Assignment::OneToOne);
+		for (i, color) in distinct.iter().enumerate() {
+			assert!(!distinct[..i].contains(color), "{harmony:?}");
+		}
+	}
+	assert!(
+		Harmony::Complementary
+			.generate_in(SEED, &[], Metric::Oklab, Assignment::Nearest)
+			.is_empty()
+	);
+}
@@ -0,0 +1,58 @@
+use bevy_color_palettes::Common;
+use bevy_color_palettes::hash::{StableHasher, color_for, index_for, stable_hash};
+use core::hash::Hasher;
+
+#[test]
+fn test_reference_hashes() {
+	// Colors assigned to keys must never change, so neither may these
+	let mut hasher = StableHasher::new();
+	hasher.write(b"a");
+	assert_eq!(hasher.finish(), 0x02c0_bdbf_4814_20f8);
+	assert_eq!(stable_hash("alice"), 0x441b_53b1_b6ba_2c50);
+	assert_eq!(stable_hash(&42_u64), 0xe15f_07fe_f55b_9454);
+	assert_eq!(stable_hash(&42_usize), stable_hash(&42_u64));
+}
+
+#[test]
+fn test_even() {
+	let mut counts = [0_u32; 8];
+	for id in 0..8000_u32 {
+		counts[index_for(&id, counts.len()).unwrap()] += 1;
+	}
+	assert!(
+		counts.iter().all(|&count| (900..1100).contains(&count)),
+		"{counts:?}"
+	);
+	assert_eq!(index_for("anything", 0), None);
+	assert_ne!(stable_hash("peer1"), stable_hash("peer2"));
+}
+
+#[test]
+fn test_color_for() {
+	let colors = Common::all();
+	let color = color_for("alice", &colors).unwrap();
+	assert!(colors.contains(&color));
+	assert_eq!(color_for("alice", &colors), Some(color));
+	assert_eq!(color_for("alice", &[]), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_avoiding() {
+	use bevy_color_palettes::hash::KeyColors;
+	use bevy_color_palettes::oklab::Oklab;
+
+	let colors = KeyColors::from_palette::<Common>()
+		.avoiding(Common::BLACK, 0.3)
+		.avoiding(Common::WHITE, 0.3);
+	assert_eq!(colors.colors().len(), Common::len());
+	assert!(colors.candidates().len() < Common::len());
+	for id in 0..100_u64 {
+		let color = colors.get(&id).unwrap();
+		assert!(Oklab::from(color).distance(Oklab::from(Common::BLACK)) >= 0.3);
+		assert!(Oklab::from(color).distance(Oklab::from(Common::WHITE)) >= 0.3);
+	}
+
+	let none = KeyColors::new(&[Common::BLACK]).avoiding(Common::BLACK, 0.1);
+	assert_eq!(none.get("alice"), None);
+}
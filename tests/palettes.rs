@@ -127,3 +127,211 @@ fn test_get() {
 	);
 	assert_eq!(TestPalette::get("nonexistent"), None);
 }
+
+#[test]
+fn test_get_normalization() {
+	// Lookups ignore case and separators without allocating
+	assert_eq!(
+		TestPalette::get("Custom-Color"),
+		Some(TestPalette::CUSTOM_COLOR)
+	);
+	assert_eq!(
+		TestPalette::get("custom color"),
+		Some(TestPalette::CUSTOM_COLOR)
+	);
+	assert_eq!(TestPalette::get("BLUE"), Some(TestPalette::BLUE));
+	assert_eq!(TestPalette::get("custom"), None);
+	assert_eq!(TestPalette::get("customColors"), None);
+	assert_eq!(TestPalette::get(""), None);
+}
+
+#[test]
+fn test_palette_table() {
+	use bevy_color_palettes::palette::Palette;
+
+	assert_eq!(TestPalette::NAME, "TestPalette");
+	assert_eq!(TestPalette::ENTRIES.len(), TestPalette::len());
+	assert_eq!(TestPalette::ENTRIES[3].name, "customColor");
+	assert_eq!(TestPalette::ENTRIES[3].color, TestPalette::CUSTOM_COLOR);
+	assert_eq!(
+		TestPalette::entry("CUSTOM_COLOR").map(|entry| entry.name),
+		Some("customColor")
+	);
+}
+
+palette!(EscapedPalette {
+	#![deny(duplicate_colors)]
+	"8bit": "#123456",
+	"type": "#654321",
+	"light-blue": "#abcdef",
+	"dark gray": "#333333",
+});
+
+#[test]
+fn test_escaped_names() {
+	assert_eq!(EscapedPalette::_8BIT, Color::new(0x12, 0x34, 0x56, 0xff));
+	assert_eq!(EscapedPalette::_8bit(), EscapedPalette::_8BIT);
+	assert_eq!(EscapedPalette::r#type(), EscapedPalette::TYPE);
+	assert_eq!(EscapedPalette::light_blue(), EscapedPalette::LIGHT_BLUE);
+	assert_eq!(EscapedPalette::get("8bit"), Some(EscapedPalette::_8BIT));
+	assert_eq!(
+		EscapedPalette::get("DarkGray"),
+		Some(EscapedPalette::DARK_GRAY)
+	);
+}
+
+#[allow(deprecated)]
+mod aliases {
+	use bevy_color_palettes::palette;
+
+	palette!(AliasPalette {
+		"dark_gray": "#333333",
+		"light_gray": "#cccccc",
+		"dark_grey": alias("dark_gray"),
+		#[deprecated(note = "renamed to `light_gray`")]
+		"silver": alias("light_gray"),
+	});
+
+	#[test]
+	fn test_aliases() {
+		assert_eq!(AliasPalette::len(), 2);
+		assert_eq!(AliasPalette::iter().count(), 2);
+		assert_eq!(AliasPalette::DARK_GREY, AliasPalette::DARK_GRAY);
+		assert_eq!(AliasPalette::silver(), AliasPalette::LIGHT_GRAY);
+		assert_eq!(AliasPalette::get("DarkGrey"), Some(AliasPalette::DARK_GRAY));
+		assert_eq!(AliasPalette::get("silver"), Some(AliasPalette::LIGHT_GRAY));
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_color_enum_serde() {
+		let gray = AliasPaletteColor::DarkGray;
+		let json = serde_json::to_string(&gray).unwrap();
+		assert_eq!(json, r#""dark_gray""#);
+		assert_eq!(
+			serde_json::from_str::<AliasPaletteColor>(&json).unwrap(),
+			gray
+		);
+
+		// Aliases and other spellings deserialize to their color, and serialize as its name
+		let silver: AliasPaletteColor = serde_json::from_str(r#""Silver""#).unwrap();
+		assert_eq!(silver, AliasPaletteColor::LightGray);
+		assert_eq!(serde_json::to_string(&silver).unwrap(), r#""light_gray""#);
+		assert_eq!(
+			serde_json::from_str::<AliasPaletteColor>(r#""DARK-GREY""#).unwrap(),
+			gray
+		);
+
+		let error = serde_json::from_str::<AliasPaletteColor>(r#""crimson""#).unwrap_err();
+		assert!(
+			error
+				.to_string()
+				.contains("a color name of AliasPaletteColor")
+		);
+		assert!(serde_json::from_str::<AliasPaletteColor>("1").is_err());
+	}
+}
+
+palette!(DerivedPalette {
+	"hover": lighten(primary, 0.1),
+	"primary": "#3b82f6",
+	"overlay": with_alpha("#000000", 0.5),
+	"gray": mix(black, white, 0.5),
+	"black": "#000000",
+	"white": "#ffffff",
+	"ink": alias("black"),
+	"shadow": darken(ink, 0.5),
+	"same": rotate_hue(desaturate("primary", 0), 360),
+	"neutral": rotate_hue(gray, 90),
+	"washed": desaturate(primary, 1.0),
+});
+
+#[test]
+fn test_derived_colors() {
+	let primary = DerivedPalette::PRIMARY;
+	assert_eq!(DerivedPalette::SAME, primary);
+	assert_eq!(DerivedPalette::OVERLAY, Color::new(0, 0, 0, 128));
+	assert_eq!(DerivedPalette::SHADOW, DerivedPalette::BLACK);
+
+	let hover = DerivedPalette::HOVER;
+	assert!(hover.r8 > primary.r8 && hover.g8 > primary.g8 && hover.b8 >= primary.b8);
+
+	let gray = DerivedPalette::GRAY;
+	assert!(gray.r8 == gray.g8 && gray.g8 == gray.b8 && (90..110).contains(&gray.r8));
+	assert_eq!(DerivedPalette::NEUTRAL, gray);
+
+	let washed = DerivedPalette::WASHED;
+	assert!(washed.r8.abs_diff(washed.b8) <= 1 && washed.g8.abs_diff(washed.b8) <= 1);
+	assert_eq!(DerivedPalette::len(), 10);
+}
+
+palette!(DeniedDuplicatesPalette {
+	#![deny(duplicate_colors)]
+	"primary": "#3b82f6",
+	"hover": lighten(primary, 0.1),
+	"pressed": darken(primary, 0.1),
+});
+
+#[test]
+fn test_derived_colors_with_denied_duplicates() {
+	assert_ne!(
+		DeniedDuplicatesPalette::HOVER,
+		DeniedDuplicatesPalette::PRESSED
+	);
+	assert_eq!(DeniedDuplicatesPalette::len(), 3);
+}
+
+#[test]
+fn test_color_enum() {
+	let custom = TestPaletteColor::CustomColor;
+	assert_eq!(TestPaletteColor::ALL.len(), TestPalette::len());
+	assert_eq!(custom.index(), 3);
+	assert_eq!(TestPaletteColor::from_index(3), Some(custom));
+	assert_eq!(TestPaletteColor::from_index(4), None);
+	assert_eq!(custom.name(), "customColor");
+	assert_eq!(custom.color(), TestPalette::CUSTOM_COLOR);
+	assert_eq!(Color::from(custom), TestPalette::CUSTOM_COLOR);
+	assert_eq!(custom.to_string(), "customColor");
+	assert_eq!("CUSTOM_COLOR".parse::<TestPaletteColor>(), Ok(custom));
+	assert!("nonexistent".parse::<TestPaletteColor>().is_err());
+	assert_eq!(EscapedPaletteColor::_8bit.color(), EscapedPalette::_8BIT);
+}
+
+#[cfg(feature = "reflect")]
+#[test]
+fn test_color_enum_reflect() {
+	use bevy::reflect::Reflect;
+
+	let custom: Box<dyn Reflect> = Box::new(TestPaletteColor::CustomColor);
+	assert!(
+		custom
+			.reflect_partial_eq(&TestPaletteColor::CustomColor)
+			.unwrap_or(false)
+	);
+}
+
+mod private {
+	use bevy_color_palettes::palette;
+
+	palette!(
+		/// A palette that is only used inside this module.
+		#[derive(PartialEq, Eq, Hash)]
+		pub(crate) PrivatePalette {
+			/// The only color.
+			"only": "#102030",
+		}
+	);
+
+	palette!(#[cfg(any())] ConfiguredOut { "gone": "#000" });
+}
+
+#[test]
+fn test_visibility_and_attributes() {
+	let mut set = std::collections::HashSet::new();
+	assert!(set.insert(private::PrivatePalette));
+	assert_eq!(
+		private::PrivatePalette::ONLY,
+		Color::new(0x10, 0x20, 0x30, 0xff)
+	);
+	assert_eq!(private::PrivatePaletteColor::Only.name(), "only");
+}
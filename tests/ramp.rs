@@ -0,0 +1,107 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::oklab::Oklch;
+use bevy_color_palettes::ramp::{self, COOL_HUE, DetectSpec, RampSpec, Ramps, WARM_HUE};
+
+fn hue_distance(a: f32, b: f32) -> f32 {
+	let delta = (a - b).rem_euclid(360.0);
+	delta.min(360.0 - delta)
+}
+
+#[test]
+fn test_ramp_shape() {
+	let base = Color::new(0x59, 0xc1, 0x35, 0xff);
+	let spec = RampSpec {
+		steps: 7,
+		..RampSpec::default()
+	};
+	let generated = ramp::generate(base, &spec);
+	assert_eq!(generated.len(), 7);
+
+	let lch: Vec<Oklch> = generated.iter().map(|&c| Oklch::from(c)).collect();
+	assert!(lch.windows(2).all(|pair| pair[0].l < pair[1].l));
+	assert!((lch[0].l - 0.25).abs() < 0.01 && (lch[6].l - 0.9).abs() < 0.01);
+
+	let base_hue = Oklch::from(base).h;
+	assert!(hue_distance(lch[0].h, COOL_HUE) < hue_distance(base_hue, COOL_HUE));
+	assert!(hue_distance(lch[6].h, WARM_HUE) < hue_distance(base_hue, WARM_HUE));
+}
+
+#[test]
+fn test_ramp_without_shift() {
+	let base = Color::new(0xb4, 0x20, 0x2a, 0xff);
+	let spec = RampSpec {
+		steps: 4,
+		hue_shift: 0.0,
+		lightness: (0.3, 0.8),
+		saturation: ramp::constant,
+	};
+	let base_hue = Oklch::from(base).h;
+	for color in ramp::generate(base, &spec) {
+		assert!(hue_distance(Oklch::from(color).h, base_hue) < 3.0);
+	}
+	assert_eq!(ramp::generate(base, &RampSpec { steps: 0, ..spec }), []);
+}
+
+#[test]
+fn test_detect_generated_ramps() {
+	let grass = ramp::generate(Color::new(0x59, 0xc1, 0x35, 0xff), &RampSpec::default());
+	let brick = ramp::generate(Color::new(0xb4, 0x20, 0x2a, 0xff), &RampSpec::default());
+	let mut palette: Vec<Color> = grass.iter().chain(&brick).copied().collect();
+	palette.reverse();
+	palette.swap(1, 6);
+
+	let ramps = Ramps::detect(&palette);
+	assert_eq!(ramps.len(), 2);
+	assert_eq!(ramps.ramp_of(grass[2]), Some(grass.clone()));
+	assert_eq!(ramps.ramp_of(brick[0]), Some(brick.clone()));
+
+	assert_eq!(ramps.shade(grass[2], -1), Some(grass[1]));
+	assert_eq!(ramps.shade(grass[2], 2), Some(grass[4]));
+	assert_eq!(ramps.shade(grass[2], 10), Some(grass[4]));
+	assert_eq!(ramps.shade(brick[1], -10), Some(brick[0]));
+
+	// Colors outside the palette shade from their closest palette color
+	let near_brick = Color::new(brick[3].r8 ^ 1, brick[3].g8, brick[3].b8, 0xff);
+	assert_eq!(ramps.shade(near_brick, -1), Some(brick[2]));
+	assert_eq!(Ramps::detect(&[]).shade(near_brick, 1), None);
+}
+
+#[test]
+fn test_detect_without_min_lightness_step() {
+	let gray = Color::new(0x80, 0x80, 0x80, 0xff);
+	let palette = [gray, gray, Color::new(0x90, 0x90, 0x90, 0xff)];
+	let spec = DetectSpec {
+		min_lightness_step: 0.0,
+		..DetectSpec::default()
+	};
+	let ramps = Ramps::detect_with(&palette, &spec);
+	assert_eq!(
+		ramps.iter().map(<[usize]>::len).sum::<usize>(),
+		palette.len()
+	);
+	assert_eq!(ramps.shade(gray, 1), Some(palette[2]));
+	assert_eq!(ramps.shade(palette[2], -1), Some(gray));
+}
+
+#[cfg(feature = "lospec")]
+#[test]
+fn test_detect_resurrect64() {
+	use bevy_color_palettes::resurrect::Resurrect64;
+
+	let palette = Resurrect64::all();
+	let ramps = Ramps::detect(&palette);
+	assert_eq!(
+		ramps.iter().map(<[usize]>::len).sum::<usize>(),
+		palette.len()
+	);
+	assert!(ramps.iter().any(|ramp| ramp.len() >= 4));
+	for color in palette {
+		let darker = ramps.shade(color, -1).unwrap();
+		let lighter = ramps.shade(color, 1).unwrap();
+		assert!(palette.contains(&darker) && palette.contains(&lighter));
+		assert!(Oklch::from(darker).l <= Oklch::from(color).l);
+		assert!(Oklch::from(lighter).l >= Oklch::from(color).l);
+	}
+}
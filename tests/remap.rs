@@ -0,0 +1,107 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::remap::{Assignment, Metric, remap};
+
+const COLORS: [Color; 6] = [
+	Color::new(0x14, 0x0c, 0x1c, 0xff),
+	Color::new(0x44, 0x24, 0x34, 0xff),
+	Color::new(0x30, 0x34, 0x6d, 0xff),
+	Color::new(0xd0, 0x46, 0x48, 0xff),
+	Color::new(0x6d, 0xaa, 0x2c, 0xff),
+	Color::new(0xde, 0xee, 0xd6, 0xff),
+];
+
+#[test]
+fn test_metrics() {
+	let (black, white) = (Color::new(0, 0, 0, 255), Color::new(255, 255, 255, 255));
+	for metric in [Metric::Rgb, Metric::Oklab, Metric::Ciede2000] {
+		assert!(metric.distance(black, black) < 1e-4);
+		assert!(metric.distance(black, white) > metric.distance(black, COLORS[1]));
+	}
+	assert!((Metric::Rgb.distance(black, white) - 3.0_f32.sqrt()).abs() < 1e-5);
+	assert!((Metric::Ciede2000.distance(black, white) - 100.0).abs() < 0.1);
+}
+
+#[test]
+fn test_nearest() {
+	for metric in [Metric::Rgb, Metric::Oklab, Metric::Ciede2000] {
+		let table = remap(&COLORS, &COLORS, metric, Assignment::Nearest).unwrap();
+		assert_eq!(table.mapping, [0, 1, 2, 3, 4, 5]);
+		assert!(table.max_error().unwrap().1 < 1e-3);
+	}
+
+	let target = [COLORS[0], COLORS[5]];
+	let table = remap(&COLORS, &target, Metric::Oklab, Assignment::Nearest).unwrap();
+	assert_eq!(table.mapping, [0, 0, 0, 1, 1, 1]);
+	assert_eq!(table.apply(&target)[5], COLORS[5]);
+	assert!(table.mean_error() > 0.0 && table.rms_error() >= table.mean_error());
+	assert!(remap(&COLORS, &[], Metric::Oklab, Assignment::Nearest).is_none());
+	assert!(remap(&[], &[], Metric::Oklab, Assignment::OneToOne).is_none());
+	let table = remap(&[], &COLORS, Metric::Oklab, Assignment::OneToOne).unwrap();
+	assert!(table.mapping.is_empty() && table.max_error().is_none());
+}
+
+#[test]
+fn test_one_to_one() {
+	// A shuffled target is matched back exactly
+	let shuffled = [
+		COLORS[3], COLORS[5], COLORS[0], COLORS[4], COLORS[2], COLORS[1],
+	];
+	let table = remap(&COLORS, &shuffled, Metric::Oklab, Assignment::OneToOne).unwrap();
+	assert_eq!(table.mapping, [2, 5, 4, 0, 3, 1]);
+
+	// Two near-black sources can't both take black
+	let source = [Color::new(8, 8, 8, 255), Color::new(16, 16, 16, 255)];
+	let target = [Color::new(0, 0, 0, 255), Color::new(128, 128, 128, 255)];
+	assert_eq!(
+		remap(&source, &target, Metric::Oklab, Assignment::Nearest)
+			.unwrap()
+			.mapping,
+		[0, 0]
+	);
+	let table = remap(&source, &target, Metric::Oklab, Assignment::OneToOne).unwrap();
+	assert_eq!(table.mapping, [0, 1]);
+
+	// Matches minimize the total error, checked against every permutation
+	let target = [COLORS[1], COLORS[4], COLORS[3]];
+	let source = [COLORS[5], COLORS[2], COLORS[0]];
+	let table = remap(&source, &target, Metric::Ciede2000, Assignment::OneToOne).unwrap();
+	let total: f32 = table.errors.iter().sum();
+	for p in [
+		[0, 1, 2],
+		[0, 2, 1],
+		[1, 0, 2],
+		[1, 2, 0],
+		[2, 0, 1],
+		[2, 1, 0],
+	] {
+		let other: f32 = (0..3)
+			.map(|i| Metric::Ciede2000.distance(source[i], target[p[i]]))
+			.sum();
+		assert!(total <= other + 1e-4);
+	}
+
+	// Leftover sources fall back to their nearest target
+	let target = [COLORS[0], COLORS[5]];
+	let table = remap(&COLORS, &target, Metric::Oklab, Assignment::OneToOne).unwrap();
+	assert_eq!(table.mapping.iter().filter(|&&t| t == 0).count(), 3);
+	assert_eq!((table.mapping[0], table.mapping[5]), (0, 1));
+}
+
+#[cfg(feature = "aseprite-pixel-art")]
+#[test]
+fn test_remap_palettes() {
+	use bevy_color_palettes::aseprite::{Db16, Pico8};
+	use bevy_color_palettes::remap::remap_palettes;
+
+	let table = remap_palettes::<Db16, Pico8>(Metric::Oklab, Assignment::OneToOne).unwrap();
+	let mut used = table.mapping.clone();
+	used.sort_unstable();
+	used.dedup();
+	assert_eq!(used.len(), 16);
+	assert_eq!(
+		table,
+		remap_palettes::<Db16, Pico8>(Metric::Oklab, Assignment::OneToOne).unwrap()
+	);
+}
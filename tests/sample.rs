@@ -0,0 +1,92 @@
+#![cfg(feature = "alloc")]
+
+use bevy_color_palettes::Common;
+use bevy_color_palettes::sample::Rng;
+
+#[test]
+fn test_reference_sequence() {
+	// The reference SplitMix64 outputs for seed 0; these must never change
+	let mut rng = Rng::new(0);
+	assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+	assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+	assert_eq!(rng.next_u64(), 0x06c4_5d18_8009_454f);
+}
+
+#[test]
+fn test_uniform() {
+	let mut rng = Rng::new(7);
+	let mut counts = [0_u32; 10];
+	for _ in 0..10_000 {
+		counts[rng.below(10)] += 1;
+	}
+	assert!(
+		counts.iter().all(|&count| (900..1100).contains(&count)),
+		"{counts:?}"
+	);
+	for _ in 0..1000 {
+		assert!((0.0..1.0).contains(&rng.next_f32()));
+	}
+
+	let colors = Common::all();
+	assert!(colors.contains(rng.pick(&colors).unwrap()));
+	assert_eq!(rng.pick::<u8>(&[]), None);
+	assert!(Common::all().contains(&rng.pick_entry::<Common>().color));
+}
+
+#[test]
+fn test_weighted() {
+	let mut rng = Rng::new(1);
+	let items = ['a', 'b', 'c', 'd'];
+	let weights = [3.0, 0.0, 1.0, -5.0];
+	let mut counts = [0_u32; 4];
+	for _ in 0..8000 {
+		let item = rng.pick_weighted(&items, &weights).unwrap();
+		counts[items.iter().position(|i| i == item).unwrap()] += 1;
+	}
+	assert_eq!((counts[1], counts[3]), (0, 0));
+	assert!((5700..6300).contains(&counts[0]), "{counts:?}");
+
+	assert_eq!(rng.pick_weighted(&items, &[0.0, f32::NAN]), None);
+	assert_eq!(rng.pick_weighted(&items, &[]), None);
+	assert_eq!(
+		rng.sample_weighted(&items, &[0.0, 0.0, 1.0], 3),
+		['c', 'c', 'c']
+	);
+}
+
+#[test]
+fn test_without_replacement() {
+	let colors = Common::all();
+	let mut rng = Rng::new(3);
+	let picked = rng.sample_distinct(&colors, 6);
+	assert_eq!(picked.len(), 6);
+	for (i, color) in picked.iter().enumerate() {
+		assert!(!picked[..i].contains(color));
+	}
+	assert_eq!(rng.sample_distinct(&colors, 100).len(), colors.len());
+
+	let mut shuffled = rng.shuffled(&colors);
+	assert_ne!(shuffled, colors);
+	shuffled.sort_by_key(|c| (c.r8, c.g8, c.b8, c.a8));
+	let mut sorted = colors.to_vec();
+	sorted.sort_by_key(|c| (c.r8, c.g8, c.b8, c.a8));
+	assert_eq!(shuffled, sorted);
+
+	assert_eq!(rng.sample(&colors, 20).len(), 20);
+	assert!(rng.sample::<u8>(&[], 5).is_empty());
+}
+
+#[test]
+fn test_deterministic() {
+	let colors = Common::all();
+	let picks = |seed| {
+		let mut rng = Rng::new(seed);
+		(
+			rng.sample(&colors, 8),
+			rng.sample_distinct(&colors, 4),
+			rng.shuffled(&colors),
+		)
+	};
+	assert_eq!(picks(99), picks(99));
+	assert_ne!(picks(99), picks(100));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::oklab::{Oklab, Oklch, contrast_ratio, relative_luminance};
+use bevy_color_palettes::scale::{self, RADIX, TAILWIND};
+
+const SEEDS: [Color; 6] = [
+	Color::new(0x3b, 0x82, 0xf6, 0xff),
+	Color::new(0xef, 0x44, 0x44, 0xff),
+	Color::new(0xfa, 0xcc, 0x15, 0xff),
+	Color::new(0x22, 0xc5, 0x5e, 0xff),
+	Color::new(0x6b, 0x72, 0x80, 0xff),
+	Color::new(0x1e, 0x1b, 0x4b, 0xff),
+];
+
+#[test]
+fn test_oklab_round_trip() {
+	for seed in SEEDS {
+		assert_eq!(Oklab::from(seed).to_color(), seed);
+		assert_eq!(Oklch::from(seed).to_color(), seed);
+	}
+	assert!((Oklab::from(Color::new(255, 255, 255, 255)).l - 1.0).abs() < 1e-3);
+	let vivid = Oklch::new(0.7, 0.4, 30.0, 1.0);
+	assert!(!Oklab::from(vivid).in_gamut());
+	let mapped = Oklch::from(vivid.to_color());
+	assert!((mapped.l - vivid.l).abs() < 0.01 && (mapped.h - vivid.h).abs() < 2.0);
+	assert!(mapped.c < vivid.c);
+}
+
+#[test]
+fn test_contrast_ratio() {
+	let black = Color::new(0, 0, 0, 255);
+	let white = Color::new(255, 255, 255, 255);
+	assert!((contrast_ratio(black, white) - 21.0).abs() < 1e-3);
+	assert!((contrast_ratio(white, white) - 1.0).abs() < 1e-6);
+	assert!((relative_luminance(white) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_tailwind_scale() {
+	for seed in SEEDS {
+		let generated = scale::generate(seed, &TAILWIND);
+		assert!(generated.colors.contains(&seed));
+		for target in TAILWIND.contrast {
+			let ratio = contrast_ratio(
+				generated.colors[target.lighter],
+				generated.colors[target.darker],
+			);
+			assert!(
+				ratio >= target.ratio,
+				"{seed:?}: {ratio} < {}",
+				target.ratio
+			);
+		}
+		let lightness: Vec<f32> = generated.colors.iter().map(|&c| Oklab::from(c).l).collect();
+		assert!(
+			lightness.windows(2).all(|pair| pair[0] > pair[1]),
+			"{lightness:?}"
+		);
+	}
+}
+
+#[test]
+fn test_radix_scale() {
+	for seed in SEEDS {
+		let generated = scale::generate(seed, &RADIX);
+		assert_eq!(generated.get(9), Some(seed));
+		assert_eq!(generated.iter().count(), 12);
+		for target in RADIX.contrast {
+			let ratio = contrast_ratio(
+				generated.colors[target.lighter],
+				generated.colors[target.darker],
+			);
+			assert!(
+				ratio >= target.ratio,
+				"{seed:?}: {ratio} < {}",
+				target.ratio
+			);
+		}
+	}
+}
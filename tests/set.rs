@@ -0,0 +1,113 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::Common;
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::runtime::RuntimePalette;
+use bevy_color_palettes::set::{
+	NameConflict, SetOptions, dedup, difference, intersection, merge, union,
+};
+
+const NEAR_RED: Color = Color::new(0xfe, 0x01, 0x01, 0xff);
+const ORANGE: Color = Color::new(0xff, 0x6a, 0x00, 0xff);
+const TEAL: Color = Color::new(0x00, 0x80, 0x80, 0xff);
+
+fn brand() -> RuntimePalette {
+	let mut brand = RuntimePalette::new("Brand");
+	brand.push("red", NEAR_RED);
+	brand.push("orange", ORANGE);
+	brand.push("Blue", TEAL);
+	brand
+}
+
+#[test]
+fn test_runtime_palette() {
+	let common = RuntimePalette::from_palette::<Common>();
+	assert_eq!(common.name(), "Common");
+	assert_eq!(common.len(), Common::len());
+	assert_eq!(
+		common.get("Transparent-White").unwrap().color,
+		Common::TRANSPARENT_WHITE
+	);
+	assert_eq!(common.position("missing"), None);
+
+	let colors = RuntimePalette::from_colors("Colors", &[ORANGE, ORANGE.with_alpha_u8(0x80)]);
+	let names: Vec<&str> = colors.iter().map(|entry| entry.name.as_str()).collect();
+	assert_eq!(names, ["color_ff6a00", "color_ff6a0080"]);
+}
+
+#[test]
+fn test_union() {
+	let common = RuntimePalette::from_palette::<Common>();
+	let combined = union(&common, &brand(), &SetOptions::default());
+	assert_eq!(combined.name(), "Common");
+	// The near red is a duplicate, orange is new and teal takes a renamed slot
+	assert_eq!(combined.len(), common.len() + 2);
+	assert_eq!(combined.get("red").unwrap().color, Common::RED);
+	assert_eq!(combined.get("orange").unwrap().color, ORANGE);
+	assert_eq!(combined.get("blue").unwrap().color, Common::BLUE);
+	assert_eq!(combined.get("Blue_2").unwrap().color, TEAL);
+
+	let exact = SetOptions {
+		threshold: 0.0,
+		..SetOptions::default()
+	};
+	assert_eq!(
+		union(&common, &brand(), &exact).get("red_2").unwrap().color,
+		NEAR_RED
+	);
+
+	let keep_first = SetOptions {
+		conflict: NameConflict::KeepFirst,
+		..SetOptions::default()
+	};
+	let combined = union(&common, &brand(), &keep_first);
+	assert_eq!(combined.len(), common.len() + 1);
+	assert_eq!(combined.get("blue").unwrap().color, Common::BLUE);
+
+	let keep_last = SetOptions {
+		conflict: NameConflict::KeepLast,
+		..SetOptions::default()
+	};
+	assert_eq!(
+		union(&common, &brand(), &keep_last)
+			.get("blue")
+			.unwrap()
+			.color,
+		TEAL
+	);
+}
+
+#[test]
+fn test_intersection_and_difference() {
+	let common = RuntimePalette::from_palette::<Common>();
+	let both = intersection(&common, &brand(), &SetOptions::default());
+	assert_eq!(both.len(), 1);
+	assert_eq!(both.entries()[0].color, Common::RED);
+
+	let only_brand = difference(&brand(), &common, &SetOptions::default());
+	let names: Vec<&str> = only_brand.iter().map(|entry| entry.name.as_str()).collect();
+	assert_eq!(names, ["orange", "Blue"]);
+}
+
+#[test]
+fn test_merge() {
+	let common = RuntimePalette::from_palette::<Common>();
+	let merged = merge(&common, &brand(), &SetOptions::default());
+	assert_eq!(merged.len(), common.len() + 1);
+	assert_eq!(merged.get("red").unwrap().color, NEAR_RED);
+	assert_eq!(merged.get("blue").unwrap().color, TEAL);
+	assert_eq!(merged.get("orange").unwrap().color, ORANGE);
+	assert_eq!(merged.position("red"), common.position("red"));
+}
+
+#[test]
+fn test_dedup() {
+	let mut palette = RuntimePalette::new("Dupes");
+	palette.push("a", Common::RED);
+	palette.push("b", NEAR_RED);
+	palette.push("c", Common::RED.with_alpha_u8(0x80));
+	palette.push("a", ORANGE);
+	let palette = dedup(&palette, &SetOptions::default());
+	let names: Vec<&str> = palette.iter().map(|entry| entry.name.as_str()).collect();
+	assert_eq!(names, ["a", "c", "a_2"]);
+}
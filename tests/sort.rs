@@ -0,0 +1,116 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::Common;
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::oklab::{Oklab, Oklch, relative_luminance};
+use bevy_color_palettes::runtime::RuntimePalette;
+use bevy_color_palettes::sample::Rng;
+use bevy_color_palettes::sort::{SortBy, order, sort, sort_palette};
+
+const ALL: [SortBy; 6] = [
+	SortBy::Hue,
+	SortBy::Lightness,
+	SortBy::Chroma,
+	SortBy::Luminance,
+	SortBy::Hilbert,
+	SortBy::NearestNeighbor,
+];
+
+#[test]
+fn test_permutation() {
+	let colors = Common::all();
+	for by in ALL {
+		let mut indices = order(&colors, by);
+		indices.sort_unstable();
+		assert_eq!(indices, (0..colors.len()).collect::<Vec<_>>(), "{by:?}");
+		assert!(order(&[], by).is_empty());
+	}
+}
+
+#[test]
+fn test_properties() {
+	let colors = Common::all();
+	let sorted = |by| -> Vec<Color> { order(&colors, by).into_iter().map(|i| colors[i]).collect() };
+
+	let lightness: Vec<f32> = sorted(SortBy::Lightness)
+		.iter()
+		.map(|&c| Oklch::from(c).l)
+		.collect();
+	assert!(lightness.windows(2).all(|pair| pair[0] <= pair[1]));
+	let chroma: Vec<f32> = sorted(SortBy::Chroma)
+		.iter()
+		.map(|&c| Oklch::from(c).c)
+		.collect();
+	assert!(chroma.windows(2).all(|pair| pair[0] <= pair[1]));
+	let luminance: Vec<f32> = sorted(SortBy::Luminance)
+		.into_iter()
+		.map(relative_luminance)
+		.collect();
+	assert!(luminance.windows(2).all(|pair| pair[0] <= pair[1]));
+
+	let by_hue = sorted(SortBy::Hue);
+	let grays = by_hue
+		.iter()
+		.take_while(|&&c| Oklch::from(c).c < 0.03)
+		.count();
+	assert_eq!(grays, 4);
+	let hues: Vec<f32> = by_hue[grays..].iter().map(|&c| Oklch::from(c).h).collect();
+	assert!(hues.windows(2).all(|pair| pair[0] <= pair[1]));
+	assert_eq!(by_hue[grays], Common::RED);
+}
+
+#[test]
+fn test_sort_palette() {
+	let mut palette = RuntimePalette::new("Shuffled");
+	palette.push("white", Common::WHITE);
+	palette.push("black", Common::BLACK);
+	palette.push("red", Common::RED);
+	let sorted = sort(&palette, SortBy::Lightness);
+	assert_eq!(sorted.name(), "Shuffled");
+	let names: Vec<&str> = sorted.iter().map(|entry| entry.name.as_str()).collect();
+	assert_eq!(names, ["black", "red", "white"]);
+
+	assert_eq!(sort_palette::<Common>(SortBy::Hilbert).len(), Common::len());
+}
+
+/// The sum of the distances between neighbouring colors.
+fn path_length(colors: &[Color]) -> f32 {
+	colors
+		.windows(2)
+		.map(|pair| Oklab::from(pair[0]).distance(Oklab::from(pair[1])))
+		.sum()
+}
+
+fn sorted_path(colors: &[Color], by: SortBy) -> Vec<Color> {
+	order(colors, by).into_iter().map(|i| colors[i]).collect()
+}
+
+#[test]
+fn test_paths() {
+	for seed in 0..8 {
+		let colors = Rng::new(seed).shuffled(&Common::all());
+		let original = path_length(&colors);
+		let hilbert = path_length(&sorted_path(&colors, SortBy::Hilbert));
+		let nearest = path_length(&sorted_path(&colors, SortBy::NearestNeighbor));
+		assert!(hilbert < original, "{hilbert} vs {original}");
+		assert!(nearest < original, "{nearest} vs {original}");
+	}
+}
+
+#[cfg(feature = "aseprite-software")]
+#[test]
+fn test_large_paths() {
+	use bevy_color_palettes::aseprite::{WebSafeColors, X11};
+
+	for colors in [X11::all().to_vec(), WebSafeColors::all().to_vec()] {
+		let original = path_length(&colors);
+		let hilbert = path_length(&sorted_path(&colors, SortBy::Hilbert));
+		let nearest = path_length(&sorted_path(&colors, SortBy::NearestNeighbor));
+		assert!(hilbert < original, "{hilbert} vs {original}");
+		assert!(nearest < hilbert, "{nearest} vs {hilbert}");
+		assert_eq!(
+			sorted_path(&colors, SortBy::NearestNeighbor)[0],
+			sorted_path(&colors, SortBy::Lightness)[0]
+		);
+	}
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "std")]
+
+use bevy_color_palettes::color::Color;
+use bevy_color_palettes::stats::{StatsOptions, analyze, analyze_with};
+
+const BLACK: Color = Color::new(0, 0, 0, 0xff);
+const WHITE: Color = Color::new(0xff, 0xff, 0xff, 0xff);
+const RED: Color = Color::new(0xff, 0, 0, 0xff);
+
+#[test]
+fn test_empty() {
+	let stats = analyze(&[]);
+	assert_eq!(stats.count, 0);
+	assert_eq!(stats.min_distance, None);
+	assert_eq!(stats.dominant, None);
+	assert!(stats.near_duplicates.is_empty());
+	assert!((stats.harmony - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_grays() {
+	let grays: Vec<Color> = (0..=10_u8)
+		.map(|i| Color::new(i * 25, i * 25, i * 25, 0xff))
+		.collect();
+	let stats = analyze(&grays);
+	assert_eq!(stats.gray_count, grays.len());
+	assert_eq!(stats.hue_histogram, [0; 12]);
+	assert!(stats.gray_coverage >= 0.9);
+	assert!(stats.lightness.0 < 1e-3 && stats.lightness.1 > 0.98);
+	assert_eq!(stats.dominant, Some(5));
+
+	let stats = analyze(&[BLACK, WHITE]);
+	assert!((stats.gray_coverage - 0.2).abs() < 1e-6);
+}
+
+#[test]
+fn test_distances() {
+	let near_red = Color::new(0xfc, 0x02, 0x02, 0xff);
+	let stats = analyze(&[BLACK, RED, WHITE, near_red]);
+	let (i, j, distance) = stats.min_distance.unwrap();
+	assert_eq!((i, j), (1, 3));
+	assert!(distance < 0.03);
+	assert_eq!(stats.near_duplicates.len(), 1);
+	assert!(stats.mean_distance > distance);
+	assert_eq!(stats.hue_histogram.iter().sum::<usize>(), 2);
+
+	let strict = StatsOptions {
+		near_duplicate: 0.001,
+		..StatsOptions::default()
+	};
+	assert!(
+		analyze_with(&[BLACK, RED, WHITE, near_red], &strict)
+			.near_duplicates
+			.is_empty()
+	);
+}
+
+#[test]
+fn test_harmony() {
+	// Analogous hues fit a single sector
+	let analogous = [
+		Color::new(0xff, 0x40, 0x00, 0xff),
+		Color::new(0xff, 0x80, 0x00, 0xff),
+		Color::new(0xff, 0xa0, 0x00, 0xff),
+	];
+	assert!(analyze(&analogous).harmony > 0.99);
+
+	// Hues spread evenly around the wheel fit no template well
+	let rainbow: Vec<Color> = [
+		(0xff, 0, 0),
+		(0xff, 0xff, 0),
+		(0, 0xff, 0),
+		(0, 0xff, 0xff),
+		(0, 0, 0xff),
+		(0xff, 0, 0xff),
+	]
+	.iter()
+	.map(|&(r, g, b)| Color::new(r, g, b, 0xff))
+	.collect();
+	assert!(analyze(&rainbow).harmony < analyze(&analogous).harmony);
+}
+
+#[cfg(all(feature = "lospec", feature = "aseprite-pixel-art"))]
+#[test]
+fn test_compare_palettes() {
+	use bevy_color_palettes::aseprite::Aap64;
+	use bevy_color_palettes::resurrect::Resurrect64;
+	use bevy_color_palettes::stats::analyze_palette;
+
+	for stats in [analyze_palette::<Resurrect64>(), analyze_palette::<Aap64>()] {
+		assert_eq!(stats.count, 64);
+		assert!(stats.min_distance.unwrap().2 > 0.0);
+		assert!(stats.gray_coverage >= 0.5);
+		assert!((0.0..=1.0).contains(&stats.harmony));
+		assert!(stats.to_string().contains("colors:          64"));
+	}
+}